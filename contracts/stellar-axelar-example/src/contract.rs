@@ -130,24 +130,25 @@ impl AxelarExampleInterface for AxelarExample {
 
         caller.require_auth();
 
+        let message_id = gateway.call_contract(
+            &env.current_contract_address(),
+            &destination_chain,
+            &destination_address,
+            &message,
+        );
+
         if let Some(gas_token) = gas_token {
             gas_service.pay_gas(
                 &env.current_contract_address(),
+                &message_id,
                 &destination_chain,
                 &destination_address,
                 &message,
                 &caller,
                 &gas_token,
-                &Bytes::new(env),
+                &None,
             );
         }
-
-        gateway.call_contract(
-            &env.current_contract_address(),
-            &destination_chain,
-            &destination_address,
-            &message,
-        );
     }
 
     fn send_token(
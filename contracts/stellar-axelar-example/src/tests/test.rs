@@ -4,10 +4,11 @@ use soroban_sdk::testutils::{
 use soroban_sdk::token::{self, StellarAssetClient};
 use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol};
 use stellar_axelar_gas_service::testutils::{setup_gas_service, setup_gas_token};
+use stellar_axelar_gas_service::types::GasMetadata;
 use stellar_axelar_gas_service::AxelarGasServiceClient;
 use stellar_axelar_gateway::event::{ContractCalledEvent, MessageApprovedEvent};
 use stellar_axelar_gateway::testutils::{
-    generate_proof, get_approve_hash, setup_gateway, TestSignerSet,
+    generate_proof, get_approve_hash, last_contract_call_message_id, setup_gateway, TestSignerSet,
 };
 use stellar_axelar_gateway::types::Message;
 use stellar_axelar_gateway::AxelarGatewayClient;
@@ -52,13 +53,18 @@ fn setup_app<'a>(env: &Env, chain_name: String) -> TestConfig<'a> {
     }
 }
 
-fn setup_tokens(env: &Env, user: &Address, amount: i128) -> (StellarAssetContract, Token) {
+fn setup_tokens(
+    env: &Env,
+    gas_service: &AxelarGasServiceClient,
+    user: &Address,
+    amount: i128,
+) -> (StellarAssetContract, Token) {
     let token = env.register_stellar_asset_contract_v2(user.clone());
     StellarAssetClient::new(env, &token.address())
         .mock_all_auths()
         .mint(user, &amount);
 
-    let gas_token = setup_gas_token(env, user);
+    let gas_token = setup_gas_token(env, gas_service, user);
 
     StellarAssetClient::new(env, &gas_token.address)
         .mock_all_auths()
@@ -76,6 +82,7 @@ fn gmp_example() {
     // Setup source Axelar gateway
     let source_chain = String::from_str(&env, SOURCE_CHAIN_NAME);
     let TestConfig {
+        gateway: source_gateway_client,
         gas_service: source_gas_service,
         app: source_app,
         ..
@@ -106,6 +113,9 @@ fn gmp_example() {
     };
 
     asset_client.mock_all_auths().mint(&user, &gas_amount);
+    source_gas_service
+        .mock_all_auths()
+        .add_gas_token(&asset.address());
 
     source_app.mock_all_auths().send(
         &user,
@@ -128,12 +138,13 @@ fn gmp_example() {
         user,
         source_gas_service_client.pay_gas(
             source_app.address.clone(),
+            last_contract_call_message_id(&env, &source_gateway_client),
             destination_chain.clone(),
             destination_address.clone(),
             payload.clone(),
             &user,
             gas_token.clone(),
-            &Bytes::new(&env)
+            None::<GasMetadata>
         ),
         transfer_auth
     );
@@ -159,7 +170,7 @@ fn gmp_example() {
     let message_id = String::from_str(&env, "test");
 
     // Confirming message from source Axelar gateway
-    let contract_call_event = events::fmt_last_emitted_event::<ContractCalledEvent>(&env);
+    let contract_call_event = events::fmt_emitted_event_at_idx::<ContractCalledEvent>(&env, -3);
 
     // Axelar hub signs the message approval, Signing message approval for destination
     let messages = vec![
@@ -197,6 +208,7 @@ fn its_example() {
     // Setup source app
     let source_chain = String::from_str(&env, SOURCE_CHAIN_NAME);
     let TestConfig {
+        gas_service: source_gas_service,
         its: source_its,
         app: source_app,
         ..
@@ -218,7 +230,7 @@ fn its_example() {
     let transfer_amount = 1000;
 
     // Setup tokens
-    let (token, gas_token) = setup_tokens(&env, &user, transfer_amount);
+    let (token, gas_token) = setup_tokens(&env, &source_gas_service, &user, transfer_amount);
 
     source_its
         .mock_all_auths()
@@ -1,15 +1,26 @@
-use soroban_sdk::{contract, contractimpl, token, Address, Bytes, Env, String};
+use soroban_sdk::{contract, contractimpl, token, Address, Bytes, BytesN, Env, String, Vec};
 use stellar_axelar_std::events::Event;
 use stellar_axelar_std::ttl::extend_instance_ttl;
 use stellar_axelar_std::types::Token;
-use stellar_axelar_std::{ensure, interfaces, only_operator, Operatable, Ownable, Upgradable};
+use stellar_axelar_std::{
+    ensure, interfaces, only_operator, only_owner, Operatable, Ownable, Upgradable,
+};
 
 use crate::error::ContractError;
-use crate::event::{GasAddedEvent, GasCollectedEvent, GasPaidEvent, GasRefundedEvent};
+use crate::event::{
+    FeeDistributionSetEvent, GasAddedEvent, GasCollectedEvent, GasInfoUpdatedEvent, GasPaidEvent,
+    GasRefundedEvent, GasSettledEvent, GasTokenAddedEvent, GasTokenRemovedEvent,
+};
 use crate::interface::AxelarGasServiceInterface;
+use crate::storage;
+use crate::types::{
+    FeeRecipient, GasInfo, GasMetadata, GasMetadataV1, GasPaymentId, PriorityTier,
+    GAS_PRICE_PRECISION, TOTAL_FEE_BPS,
+};
 
 #[contract]
 #[derive(Operatable, Ownable, Upgradable)]
+#[migratable]
 pub struct AxelarGasService;
 
 #[contractimpl]
@@ -26,31 +37,100 @@ impl AxelarGasServiceInterface for AxelarGasService {
     fn pay_gas(
         env: Env,
         sender: Address,
+        message_id: String,
+        destination_chain: String,
+        destination_address: String,
+        payload: Bytes,
+        spender: Address,
+        token: Token,
+        metadata: Option<GasMetadata>,
+    ) -> Result<(), ContractError> {
+        Self::pay_gas_from(
+            env,
+            sender,
+            message_id,
+            destination_chain,
+            destination_address,
+            payload,
+            spender.clone(),
+            spender,
+            token,
+            metadata,
+        )
+    }
+
+    fn pay_gas_from(
+        env: Env,
+        sender: Address,
+        message_id: String,
         destination_chain: String,
         destination_address: String,
         payload: Bytes,
         spender: Address,
+        from: Address,
         token: Token,
-        metadata: Bytes,
+        metadata: Option<GasMetadata>,
     ) -> Result<(), ContractError> {
         spender.require_auth();
 
         ensure!(token.amount > 0, ContractError::InvalidAmount);
+        Self::ensure_gas_token_accepted(&env, &token.address)?;
 
-        token::Client::new(&env, &token.address).transfer(
-            &spender,
-            &env.current_contract_address(),
-            &token.amount,
-        );
+        let GasMetadataV1 {
+            refund_address,
+            gas_limit,
+            express,
+            priority,
+        } = match metadata {
+            Some(GasMetadata::V1(metadata)) => metadata,
+            None => GasMetadataV1 {
+                refund_address: None,
+                gas_limit: None,
+                express: false,
+                priority: PriorityTier::Standard,
+            },
+        };
+        ensure!(gas_limit != Some(0), ContractError::InvalidGasMetadata);
+
+        if let Some(GasInfo {
+            min_gas_limit: Some(min_gas_limit),
+            ..
+        }) = storage::try_gas_info(&env, destination_chain.clone())
+        {
+            let min_fee = Self::estimate_gas_fee(
+                env.clone(),
+                destination_chain.clone(),
+                payload.len(),
+                gas_limit.map_or(min_gas_limit, |gas_limit| gas_limit.max(min_gas_limit)),
+                token.address.clone(),
+            )?;
+
+            ensure!(
+                token.amount >= min_fee,
+                ContractError::InsufficientGasPayment
+            );
+        }
+
+        let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+        let refund_address = refund_address.unwrap_or_else(|| from.clone());
+
+        Self::record_gas_payment(&env, message_id.clone(), refund_address.clone(), &token)?;
+
+        Self::transfer_gas(&env, &spender, &from, &token);
 
         GasPaidEvent {
             sender,
+            message_id,
             destination_chain,
             destination_address,
-            payload_hash: env.crypto().keccak256(&payload).into(),
+            payload_hash,
+            refund_address,
             spender,
             token,
-            metadata,
+            gas_limit,
+            express,
+            priority,
+            from,
         }
         .emit(&env);
 
@@ -63,22 +143,33 @@ impl AxelarGasServiceInterface for AxelarGasService {
         message_id: String,
         spender: Address,
         token: Token,
+    ) -> Result<(), ContractError> {
+        Self::add_gas_from(env, sender, message_id, spender.clone(), spender, token)
+    }
+
+    fn add_gas_from(
+        env: Env,
+        sender: Address,
+        message_id: String,
+        spender: Address,
+        from: Address,
+        token: Token,
     ) -> Result<(), ContractError> {
         spender.require_auth();
 
         ensure!(token.amount > 0, ContractError::InvalidAmount);
+        Self::ensure_gas_token_accepted(&env, &token.address)?;
 
-        token::Client::new(&env, &token.address).transfer(
-            &spender,
-            &env.current_contract_address(),
-            &token.amount,
-        );
+        Self::record_gas_payment(&env, message_id.clone(), from.clone(), &token)?;
+
+        Self::transfer_gas(&env, &spender, &from, &token);
 
         GasAddedEvent {
             sender,
             message_id,
             spender,
             token,
+            from,
         }
         .emit(&env);
 
@@ -89,15 +180,15 @@ impl AxelarGasServiceInterface for AxelarGasService {
     fn collect_fees(env: Env, receiver: Address, token: Token) -> Result<(), ContractError> {
         ensure!(token.amount > 0, ContractError::InvalidAmount);
 
-        let token_client = token::Client::new(&env, &token.address);
-
-        let contract_token_balance = token_client.balance(&env.current_contract_address());
-
         ensure!(
-            contract_token_balance >= token.amount,
+            Self::collectable_fees(&env, &token.address) >= token.amount,
             ContractError::InsufficientBalance
         );
-        token_client.transfer(&env.current_contract_address(), &receiver, &token.amount);
+        token::Client::new(&env, &token.address).transfer(
+            &env.current_contract_address(),
+            &receiver,
+            &token.amount,
+        );
 
         GasCollectedEvent { receiver, token }.emit(&env);
 
@@ -107,7 +198,36 @@ impl AxelarGasServiceInterface for AxelarGasService {
     }
 
     #[only_operator]
-    fn refund(env: Env, message_id: String, receiver: Address, token: Token) {
+    fn refund(
+        env: Env,
+        message_id: String,
+        receiver: Address,
+        token: Token,
+    ) -> Result<(), ContractError> {
+        ensure!(token.amount > 0, ContractError::InvalidAmount);
+
+        let paid_token = Self::gas_paid(env.clone(), message_id.clone(), receiver.clone())?;
+
+        ensure!(
+            paid_token.address == token.address,
+            ContractError::UnsupportedGasToken
+        );
+        ensure!(
+            paid_token.amount >= token.amount,
+            ContractError::RefundExceedsGasPaid
+        );
+
+        storage::set_gas_payment(
+            &env,
+            message_id.clone(),
+            receiver.clone(),
+            &Token {
+                address: paid_token.address,
+                amount: paid_token.amount - token.amount,
+            },
+        );
+        Self::update_refundable_gas(&env, &token.address, -token.amount)?;
+
         token::Client::new(&env, &token.address).transfer(
             &env.current_contract_address(),
             &receiver,
@@ -120,5 +240,298 @@ impl AxelarGasServiceInterface for AxelarGasService {
             token,
         }
         .emit(&env);
+
+        Ok(())
+    }
+
+    #[only_operator]
+    fn settle_gas_payments(env: Env, payments: Vec<GasPaymentId>) -> Result<(), ContractError> {
+        for GasPaymentId {
+            message_id,
+            refund_address,
+        } in payments
+        {
+            let token = Self::gas_paid(env.clone(), message_id.clone(), refund_address.clone())?;
+
+            storage::remove_gas_payment(&env, message_id.clone(), refund_address.clone());
+            Self::update_refundable_gas(&env, &token.address, -token.amount)?;
+
+            GasSettledEvent {
+                message_id,
+                refund_address,
+                token,
+            }
+            .emit(&env);
+        }
+
+        Ok(())
+    }
+
+    fn gas_paid(
+        env: Env,
+        message_id: String,
+        refund_address: Address,
+    ) -> Result<Token, ContractError> {
+        storage::try_gas_payment(&env, message_id, refund_address)
+            .ok_or(ContractError::GasPaymentNotFound)
+    }
+
+    #[only_operator]
+    fn add_gas_token(env: Env, token: Address) -> Result<(), ContractError> {
+        Self::accept_gas_token(&env, token)
+    }
+
+    #[only_operator]
+    fn remove_gas_token(env: Env, token: Address) -> Result<(), ContractError> {
+        ensure!(
+            storage::is_accepted_gas_token(&env, token.clone()),
+            ContractError::GasTokenNotAccepted
+        );
+
+        storage::remove_accepted_gas_token_status(&env, token.clone());
+
+        let mut gas_tokens = Self::gas_tokens(env.clone());
+        if let Some(index) = gas_tokens.first_index_of(&token) {
+            gas_tokens.remove(index);
+        }
+        storage::set_accepted_gas_tokens(&env, &gas_tokens);
+
+        GasTokenRemovedEvent { token }.emit(&env);
+
+        Ok(())
+    }
+
+    fn gas_tokens(env: Env) -> Vec<Address> {
+        storage::try_accepted_gas_tokens(&env).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    #[only_owner]
+    fn set_fee_distribution(env: Env, recipients: Vec<FeeRecipient>) -> Result<(), ContractError> {
+        ensure!(
+            !recipients.is_empty(),
+            ContractError::InvalidFeeDistribution
+        );
+
+        let mut total_bps: u32 = 0;
+        for FeeRecipient { bps, .. } in recipients.iter() {
+            ensure!(bps > 0, ContractError::InvalidFeeDistribution);
+            total_bps = total_bps
+                .checked_add(bps)
+                .ok_or(ContractError::InvalidFeeDistribution)?;
+        }
+        ensure!(
+            total_bps == TOTAL_FEE_BPS,
+            ContractError::InvalidFeeDistribution
+        );
+
+        storage::set_fee_distribution(&env, &recipients);
+
+        FeeDistributionSetEvent { recipients }.emit(&env);
+
+        Ok(())
+    }
+
+    fn fee_distribution(env: Env) -> Result<Vec<FeeRecipient>, ContractError> {
+        storage::try_fee_distribution(&env).ok_or(ContractError::FeeDistributionNotSet)
+    }
+
+    #[only_operator]
+    fn distribute_fees(env: Env, tokens: Vec<Address>) -> Result<(), ContractError> {
+        let recipients = Self::fee_distribution(env.clone())?;
+        let last_recipient_index = recipients.len() - 1;
+
+        for token_address in tokens.into_iter() {
+            let token_client = token::Client::new(&env, &token_address);
+            let balance = Self::collectable_fees(&env, &token_address);
+            let mut remaining = balance;
+
+            for (index, FeeRecipient { receiver, bps }) in recipients.iter().enumerate() {
+                let amount = if index as u32 == last_recipient_index {
+                    remaining
+                } else {
+                    Self::fee_share(balance, bps)
+                };
+
+                if amount == 0 {
+                    continue;
+                }
+                remaining -= amount;
+
+                token_client.transfer(&env.current_contract_address(), &receiver, &amount);
+
+                GasCollectedEvent {
+                    receiver,
+                    token: Token {
+                        address: token_address.clone(),
+                        amount,
+                    },
+                }
+                .emit(&env);
+            }
+        }
+
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    #[only_operator]
+    fn set_gas_info(
+        env: Env,
+        destination_chain: String,
+        gas_info: GasInfo,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            gas_info.base_fee >= 0 && gas_info.gas_price >= 0,
+            ContractError::InvalidGasInfo
+        );
+        Self::ensure_gas_token_accepted(&env, &gas_info.token)?;
+
+        storage::set_gas_info(&env, destination_chain.clone(), &gas_info);
+
+        GasInfoUpdatedEvent {
+            destination_chain,
+            gas_info,
+        }
+        .emit(&env);
+
+        Ok(())
+    }
+
+    fn gas_info(env: Env, destination_chain: String) -> Result<GasInfo, ContractError> {
+        storage::try_gas_info(&env, destination_chain).ok_or(ContractError::GasInfoNotFound)
+    }
+
+    fn estimate_gas_fee(
+        env: Env,
+        destination_chain: String,
+        payload_len: u32,
+        gas_limit: u64,
+        token: Address,
+    ) -> Result<i128, ContractError> {
+        let gas_info = Self::gas_info(env, destination_chain)?;
+
+        ensure!(gas_info.token == token, ContractError::UnsupportedGasToken);
+
+        i128::from(payload_len)
+            .checked_mul(gas_info.payload_byte_gas.into())
+            .and_then(|payload_gas| payload_gas.checked_add(gas_limit.into()))
+            .and_then(|gas| gas.checked_mul(gas_info.gas_price))
+            .map(|gas_fee| gas_fee / GAS_PRICE_PRECISION)
+            .and_then(|gas_fee| gas_fee.checked_add(gas_info.base_fee))
+            .ok_or(ContractError::GasFeeOverflow)
+    }
+}
+
+impl AxelarGasService {
+    /// Accepts `token` for gas payments.
+    pub(crate) fn accept_gas_token(env: &Env, token: Address) -> Result<(), ContractError> {
+        ensure!(
+            !storage::is_accepted_gas_token(env, token.clone()),
+            ContractError::GasTokenAlreadyAccepted
+        );
+
+        storage::set_accepted_gas_token_status(env, token.clone());
+
+        let mut gas_tokens = Self::gas_tokens(env.clone());
+        gas_tokens.push_back(token.clone());
+        storage::set_accepted_gas_tokens(env, &gas_tokens);
+
+        GasTokenAddedEvent { token }.emit(env);
+
+        Ok(())
+    }
+
+    /// The accepted gas tokens are the only tokens that gas can be paid or priced in.
+    fn ensure_gas_token_accepted(env: &Env, token: &Address) -> Result<(), ContractError> {
+        ensure!(
+            storage::is_accepted_gas_token(env, token.clone()),
+            ContractError::GasTokenNotAccepted
+        );
+
+        Ok(())
+    }
+
+    /// The `bps` share of `amount`, rounded down, without overflowing for large amounts.
+    fn fee_share(amount: i128, bps: u32) -> i128 {
+        let total_bps = i128::from(TOTAL_FEE_BPS);
+        let bps = i128::from(bps);
+
+        amount / total_bps * bps + amount % total_bps * bps / total_bps
+    }
+
+    /// Adds the gas `token` paid for `message_id` to the gas that can be refunded to `refund_address`.
+    fn record_gas_payment(
+        env: &Env,
+        message_id: String,
+        refund_address: Address,
+        token: &Token,
+    ) -> Result<(), ContractError> {
+        let gas_payment =
+            match storage::try_gas_payment(env, message_id.clone(), refund_address.clone()) {
+                Some(paid_token) => {
+                    ensure!(
+                        paid_token.address == token.address,
+                        ContractError::UnsupportedGasToken
+                    );
+
+                    Token {
+                        address: paid_token.address,
+                        amount: paid_token
+                            .amount
+                            .checked_add(token.amount)
+                            .ok_or(ContractError::InvalidAmount)?,
+                    }
+                }
+                None => token.clone(),
+            };
+        storage::set_gas_payment(env, message_id, refund_address, &gas_payment);
+        Self::update_refundable_gas(env, &token.address, token.amount)?;
+
+        Ok(())
+    }
+
+    /// Adds `amount` to the total gas paid in `token` that can still be refunded.
+    fn update_refundable_gas(
+        env: &Env,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        let refundable_gas = storage::try_refundable_gas(env, token.clone())
+            .unwrap_or(0)
+            .checked_add(amount)
+            .ok_or(ContractError::InvalidAmount)?;
+
+        if refundable_gas == 0 {
+            storage::remove_refundable_gas(env, token.clone());
+        } else {
+            storage::set_refundable_gas(env, token.clone(), &refundable_gas);
+        }
+
+        Ok(())
+    }
+
+    /// The balance of `token` that can be collected as fees, i.e. excluding gas that can still be refunded.
+    fn collectable_fees(env: &Env, token: &Address) -> i128 {
+        let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+        let refundable_gas = storage::try_refundable_gas(env, token.clone()).unwrap_or(0);
+
+        balance.saturating_sub(refundable_gas).max(0)
+    }
+
+    /// Transfers the gas `token` from `from` to the gas service, using the allowance of `spender` if they differ.
+    fn transfer_gas(env: &Env, spender: &Address, from: &Address, token: &Token) {
+        let token_client = token::Client::new(env, &token.address);
+
+        if spender == from {
+            token_client.transfer(spender, &env.current_contract_address(), &token.amount);
+        } else {
+            token_client.transfer_from(
+                spender,
+                from,
+                &env.current_contract_address(),
+                &token.amount,
+            );
+        }
     }
 }
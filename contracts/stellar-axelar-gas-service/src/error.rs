@@ -8,4 +8,16 @@ pub enum ContractError {
     InvalidAddress = 2,
     InvalidAmount = 3,
     InsufficientBalance = 4,
+    GasInfoNotFound = 5,
+    InvalidGasInfo = 6,
+    UnsupportedGasToken = 7,
+    InsufficientGasPayment = 8,
+    GasFeeOverflow = 9,
+    GasPaymentNotFound = 10,
+    RefundExceedsGasPaid = 11,
+    GasTokenNotAccepted = 12,
+    GasTokenAlreadyAccepted = 13,
+    InvalidFeeDistribution = 14,
+    FeeDistributionNotSet = 15,
+    InvalidGasMetadata = 16,
 }
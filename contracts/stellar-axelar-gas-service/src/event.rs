@@ -1,17 +1,28 @@
-use soroban_sdk::{Address, Bytes, BytesN, String};
+use soroban_sdk::{Address, BytesN, String, Vec};
 use stellar_axelar_std::types::Token;
 use stellar_axelar_std::IntoEvent;
 
+use crate::types::{FeeRecipient, GasInfo, PriorityTier};
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct GasPaidEvent {
     pub sender: Address,
+    pub message_id: String,
     pub destination_chain: String,
     pub destination_address: String,
     pub payload_hash: BytesN<32>,
     pub spender: Address,
     pub token: Token,
     #[data]
-    pub metadata: Bytes,
+    pub refund_address: Address,
+    #[data]
+    pub gas_limit: Option<u64>,
+    #[data]
+    pub express: bool,
+    #[data]
+    pub priority: PriorityTier,
+    #[data]
+    pub from: Address,
 }
 
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
@@ -20,6 +31,8 @@ pub struct GasAddedEvent {
     pub message_id: String,
     pub spender: Address,
     pub token: Token,
+    #[data]
+    pub from: Address,
 }
 
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
@@ -29,8 +42,38 @@ pub struct GasRefundedEvent {
     pub token: Token,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasSettledEvent {
+    pub message_id: String,
+    pub refund_address: Address,
+    pub token: Token,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct GasCollectedEvent {
     pub receiver: Address,
     pub token: Token,
 }
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasInfoUpdatedEvent {
+    pub destination_chain: String,
+    #[data]
+    pub gas_info: GasInfo,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasTokenAddedEvent {
+    pub token: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct GasTokenRemovedEvent {
+    pub token: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct FeeDistributionSetEvent {
+    #[data]
+    pub recipients: Vec<FeeRecipient>,
+}
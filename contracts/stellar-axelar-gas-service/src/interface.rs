@@ -1,40 +1,80 @@
-use soroban_sdk::{contractclient, Address, Bytes, Env, String};
-use stellar_axelar_std::interfaces::OperatableInterface;
+use soroban_sdk::{contractclient, Address, Bytes, Env, String, Vec};
+use stellar_axelar_std::interfaces::{OperatableInterface, OwnableInterface};
 use stellar_axelar_std::types::Token;
 
 use crate::error::ContractError;
+use crate::types::{FeeRecipient, GasInfo, GasMetadata, GasPaymentId};
 
 #[contractclient(name = "AxelarGasServiceClient")]
-pub trait AxelarGasServiceInterface: OperatableInterface {
+pub trait AxelarGasServiceInterface: OwnableInterface + OperatableInterface {
     /// Pay for gas using a token for sending a message on a destination chain.
     ///
-    /// This function is called on the source chain before calling the gateway to send a message.
+    /// This function is called on the source chain after calling the gateway to send a message,
+    /// with the message id returned by the gateway.
     /// The `spender` pays the gas but might differ from the `sender`,
     /// e.g. the `sender` is a contract, but the `spender` can be the user signing the transaction.
     ///
     /// # Arguments
     /// * `sender` - The address initiating the gas payment. It's the address that sent the cross-chain message via the `axelar_gateway`.
+    /// * `message_id` - The identifier of the message, as returned by the `axelar_gateway`.
     /// * `destination_chain` - The destination chain for the message.
     /// * `destination_address` - The destination contract address for the message.
     /// * `payload` - The payload data associated with the message.
-    /// * `spender` - The address of the spender paying for the gas. Might differ from the `sender`. Excess gas will be refunded to this address.
+    /// * `spender` - The address of the spender paying for the gas. Might differ from the `sender`. Excess gas will be refunded to this address, unless `metadata` sets a refund address.
     /// * `token` - The token used to pay for the gas, including the address and amount.
-    /// * `metadata` - Additional metadata associated with the gas payment.
+    /// * `metadata` - How relayers should deliver the message, see [`GasMetadata`]. Defaults apply if not set.
+    ///
+    /// If a minimum fee is enforced for `destination_chain`, it covers at least the gas limit set in `metadata`.
     ///
     /// # Errors
     /// - [`ContractError::InvalidAmount`]: If the token amount is zero or negative.
+    /// - [`ContractError::InvalidGasMetadata`]: If the gas limit set in `metadata` is zero.
+    /// - [`ContractError::GasTokenNotAccepted`]: If the token is not an accepted gas token.
+    /// - [`ContractError::UnsupportedGasToken`]: If a minimum fee is enforced for `destination_chain` and the token is not its gas token.
+    /// - [`ContractError::InsufficientGasPayment`]: If a minimum fee is enforced for `destination_chain` and the token amount is below it.
     ///
     /// # Authorization
     /// - The `spender` address must authorize the token transfer to the gas service.
     fn pay_gas(
         env: Env,
         sender: Address,
+        message_id: String,
+        destination_chain: String,
+        destination_address: String,
+        payload: Bytes,
+        spender: Address,
+        token: Token,
+        metadata: Option<GasMetadata>,
+    ) -> Result<(), ContractError>;
+
+    /// Pay for gas out of the allowance that `from` granted to `spender` on the gas token.
+    ///
+    /// Behaves like [`Self::pay_gas`], except the gas is pulled from `from` with `transfer_from`,
+    /// e.g. for a dApp contract paying out of a user's allowance, or a sponsor account funding the gas of others.
+    /// Excess gas is refunded to `from` unless `metadata` sets a refund address.
+    ///
+    /// # Arguments
+    /// * `spender` - The address spending the allowance. Might be the same as `from`, in which case no allowance is needed.
+    /// * `from` - The address whose tokens pay for the gas.
+    ///
+    /// See [`Self::pay_gas`] for the other arguments.
+    ///
+    /// # Errors
+    /// See [`Self::pay_gas`].
+    ///
+    /// # Authorization
+    /// - The `spender` address must authorize.
+    fn pay_gas_from(
+        env: Env,
+        sender: Address,
+        message_id: String,
         destination_chain: String,
         destination_address: String,
         payload: Bytes,
         spender: Address,
+        from: Address,
         token: Token,
-        metadata: Bytes,
+        metadata: Option<GasMetadata>,
     ) -> Result<(), ContractError>;
 
     /// Adds additional gas payment after initiating a cross-chain message.
@@ -48,8 +88,13 @@ pub trait AxelarGasServiceInterface: OperatableInterface {
     /// * `spender` - The address of the spender paying for the gas.
     /// * `token` - The token used to pay for the gas, including the address and amount.
     ///
+    /// The payment is added to the gas paid for `message_id` by the `spender`, which it can be refunded to.
+    /// Payments of different spenders for the same message are recorded separately.
+    ///
     /// # Errors
     /// - [`ContractError::InvalidAmount`]: If the token amount is zero or negative.
+    /// - [`ContractError::GasTokenNotAccepted`]: If the token is not an accepted gas token.
+    /// - [`ContractError::UnsupportedGasToken`]: If the `spender` already paid gas for `message_id` in a different token.
     ///
     /// # Authorization
     /// - The `spender` address must authorize.
@@ -61,9 +106,35 @@ pub trait AxelarGasServiceInterface: OperatableInterface {
         token: Token,
     ) -> Result<(), ContractError>;
 
+    /// Adds additional gas payment out of the allowance that `from` granted to `spender` on the gas token.
+    ///
+    /// Behaves like [`Self::add_gas`], except the gas is pulled from `from` with `transfer_from`.
+    /// The payment can be refunded to `from`.
+    ///
+    /// # Arguments
+    /// * `spender` - The address spending the allowance. Might be the same as `from`, in which case no allowance is needed.
+    /// * `from` - The address whose tokens pay for the gas.
+    ///
+    /// See [`Self::add_gas`] for the other arguments.
+    ///
+    /// # Errors
+    /// See [`Self::add_gas`].
+    ///
+    /// # Authorization
+    /// - The `spender` address must authorize.
+    fn add_gas_from(
+        env: Env,
+        sender: Address,
+        message_id: String,
+        spender: Address,
+        from: Address,
+        token: Token,
+    ) -> Result<(), ContractError>;
+
     /// Collects gas fees and transfers them to a specified receiver.
     ///
     /// Allows the `gas_collector` to collect accumulated fees from the contract.
+    /// Gas that can still be refunded can't be collected until it is settled with [`Self::settle_gas_payments`].
     ///
     /// # Arguments
     /// * `receiver` - The address that will receive the collected feeds.
@@ -71,7 +142,7 @@ pub trait AxelarGasServiceInterface: OperatableInterface {
     ///
     /// # Errors
     /// - [`ContractError::InvalidAmount`]: If the token amount is zero or negative.
-    /// - [`ContractError::InsufficientBalance`]: If the contract's token balance is insufficient to cover the transfer.
+    /// - [`ContractError::InsufficientBalance`]: If the contract's token balance, excluding gas that can still be refunded, is insufficient to cover the transfer.
     ///
     /// # Authorization
     /// - [`OperatableInterface::operator`] must authorize.
@@ -79,12 +150,146 @@ pub trait AxelarGasServiceInterface: OperatableInterface {
 
     /// Refunds gas payment to the specified receiver in relation to a specific cross-chain message.
     ///
+    /// The refund is capped at the gas paid for the message that is refundable to `receiver`, minus previous refunds.
+    ///
     /// # Arguments
-    /// * `message_id` - The identifier of the cross-chain message for which the gas fees are being refunded.
+    /// * `message_id` - The identifier of the message for which the gas fees are being refunded.
     /// * `receiver` - The address of the receiver to whom the gas fees will be refunded.
     /// * `token` - The token used for the refund, including the address and amount.
     ///
+    /// # Errors
+    /// - [`ContractError::InvalidAmount`]: If the token amount is zero or negative.
+    /// - [`ContractError::GasPaymentNotFound`]: If no gas refundable to `receiver` was paid for `message_id`.
+    /// - [`ContractError::UnsupportedGasToken`]: If the gas for the message was paid in a different token.
+    /// - [`ContractError::RefundExceedsGasPaid`]: If the token amount exceeds the gas left to refund for the message.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn refund(
+        env: Env,
+        message_id: String,
+        receiver: Address,
+        token: Token,
+    ) -> Result<(), ContractError>;
+
+    /// Settles gas payments that are no longer refundable, e.g. once their messages have been executed
+    /// and any excess gas has been refunded.
+    ///
+    /// Gas paid is reserved for refunds until it is settled, and can't be collected as fees before.
+    /// The operator is expected to settle gas payments in batches, before collecting or distributing fees.
+    ///
+    /// # Errors
+    /// - [`ContractError::GasPaymentNotFound`]: If any of the `payments` wasn't paid or was already settled.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn settle_gas_payments(env: Env, payments: Vec<GasPaymentId>) -> Result<(), ContractError>;
+
+    /// Returns the gas paid for the message `message_id` that is refundable to `refund_address`, net of refunds.
+    ///
+    /// Gas paid for a message is recorded per refund address, so that one payer can't redirect the refund of another's payment.
+    ///
+    /// # Errors
+    /// - [`ContractError::GasPaymentNotFound`]: If no gas refundable to `refund_address` was paid for `message_id`.
+    fn gas_paid(
+        env: Env,
+        message_id: String,
+        refund_address: Address,
+    ) -> Result<Token, ContractError>;
+
+    /// Accepts a token for gas payments.
+    ///
+    /// # Errors
+    /// - [`ContractError::GasTokenAlreadyAccepted`]: If the token is already accepted.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn add_gas_token(env: Env, token: Address) -> Result<(), ContractError>;
+
+    /// Stops accepting a token for gas payments.
+    ///
+    /// # Errors
+    /// - [`ContractError::GasTokenNotAccepted`]: If the token is not accepted.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn remove_gas_token(env: Env, token: Address) -> Result<(), ContractError>;
+
+    /// Returns the tokens accepted for gas payments.
+    fn gas_tokens(env: Env) -> Vec<Address>;
+
+    /// Sets the receivers that collected fees are distributed to by [`Self::distribute_fees`].
+    ///
+    /// # Arguments
+    /// * `recipients` - The receivers and their shares of the fees in basis points.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidFeeDistribution`]: If there are no recipients, a share is zero, or the shares don't add up to [`crate::types::TOTAL_FEE_BPS`].
+    ///
+    /// # Authorization
+    /// - [`OwnableInterface::owner`] must authorize.
+    fn set_fee_distribution(env: Env, recipients: Vec<FeeRecipient>) -> Result<(), ContractError>;
+
+    /// Returns the receivers that collected fees are distributed to.
+    ///
+    /// # Errors
+    /// - [`ContractError::FeeDistributionNotSet`]: If no fee distribution is set.
+    fn fee_distribution(env: Env) -> Result<Vec<FeeRecipient>, ContractError>;
+
+    /// Distributes the contract's balance of each of the `tokens` across the fee distribution recipients.
+    ///
+    /// Gas that can still be refunded is excluded, until it is settled with [`Self::settle_gas_payments`].
+    ///
+    /// A [`crate::event::GasCollectedEvent`] is emitted for every recipient of every token. Rounding dust goes to the last recipient.
+    ///
+    /// # Errors
+    /// - [`ContractError::FeeDistributionNotSet`]: If no fee distribution is set.
+    ///
+    /// # Authorization
+    /// - [`OperatableInterface::operator`] must authorize.
+    fn distribute_fees(env: Env, tokens: Vec<Address>) -> Result<(), ContractError>;
+
+    /// Sets the gas pricing of a destination chain.
+    ///
+    /// # Arguments
+    /// * `destination_chain` - The destination chain the gas pricing applies to.
+    /// * `gas_info` - The gas pricing, see [`GasInfo`].
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidGasInfo`]: If the base fee or gas price is negative.
+    /// - [`ContractError::GasTokenNotAccepted`]: If the gas token of `gas_info` is not an accepted gas token.
+    ///
     /// # Authorization
     /// - [`OperatableInterface::operator`] must authorize.
-    fn refund(env: Env, message_id: String, receiver: Address, token: Token);
+    fn set_gas_info(
+        env: Env,
+        destination_chain: String,
+        gas_info: GasInfo,
+    ) -> Result<(), ContractError>;
+
+    /// Returns the gas pricing of a destination chain.
+    ///
+    /// # Errors
+    /// - [`ContractError::GasInfoNotFound`]: If no gas pricing is set for `destination_chain`.
+    fn gas_info(env: Env, destination_chain: String) -> Result<GasInfo, ContractError>;
+
+    /// Estimates the fee, in `token` units, of sending a message to a destination chain.
+    ///
+    /// # Arguments
+    /// * `destination_chain` - The destination chain for the message.
+    /// * `payload_len` - The length of the message payload in bytes.
+    /// * `gas_limit` - The gas limit of executing the message on the destination chain.
+    /// * `token` - The token the gas will be paid in.
+    ///
+    /// # Errors
+    /// - [`ContractError::GasInfoNotFound`]: If no gas pricing is set for `destination_chain`.
+    /// - [`ContractError::UnsupportedGasToken`]: If `token` is not the gas token of `destination_chain`.
+    /// - [`ContractError::GasFeeOverflow`]: If the fee overflows.
+    fn estimate_gas_fee(
+        env: Env,
+        destination_chain: String,
+        payload_len: u32,
+        gas_limit: u64,
+        token: Address,
+    ) -> Result<i128, ContractError>;
 }
@@ -4,6 +4,7 @@
 extern crate std;
 
 pub mod error;
+pub mod types;
 
 mod interface;
 
@@ -21,7 +22,9 @@ cfg_if::cfg_if! {
         pub use interface::{AxelarGasServiceClient, AxelarGasServiceInterface};
     } else {
         pub mod event;
+        mod storage;
         mod contract;
+        mod migrate;
 
         pub use contract::{AxelarGasService, AxelarGasServiceClient};
     }
@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env, Vec};
+use stellar_axelar_std::interfaces::CustomMigratableInterface;
+
+use crate::error::ContractError;
+use crate::AxelarGasService;
+
+impl CustomMigratableInterface for AxelarGasService {
+    /// The tokens that gas is paid in, which were accepted before gas tokens had to be added by the operator.
+    type MigrationData = Vec<Address>;
+    type Error = ContractError;
+
+    fn __migrate(env: &Env, migration_data: Self::MigrationData) -> Result<(), Self::Error> {
+        for token in migration_data {
+            Self::accept_gas_token(env, token)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, String, Vec};
+use stellar_axelar_std::contractstorage;
+use stellar_axelar_std::types::Token;
+
+use crate::types::{FeeRecipient, GasInfo};
+
+#[contractstorage]
+#[derive(Clone, Debug)]
+enum DataKey {
+    #[persistent]
+    #[value(GasInfo)]
+    GasInfo { destination_chain: String },
+
+    #[persistent]
+    #[value(Token)]
+    GasPayment {
+        message_id: String,
+        refund_address: Address,
+    },
+
+    #[persistent]
+    #[value(i128)]
+    RefundableGas { token: Address },
+
+    #[persistent]
+    #[status]
+    AcceptedGasToken { token: Address },
+
+    #[persistent]
+    #[value(Vec<Address>)]
+    AcceptedGasTokens,
+
+    #[instance]
+    #[value(Vec<FeeRecipient>)]
+    FeeDistribution,
+}
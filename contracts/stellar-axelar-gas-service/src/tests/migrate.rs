@@ -0,0 +1,48 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+use stellar_axelar_std::assert_err;
+use stellar_axelar_std::interfaces::CustomMigratableInterface;
+
+use crate::error::ContractError;
+use crate::{AxelarGasService, AxelarGasServiceClient};
+
+fn setup_env<'a>() -> (Env, AxelarGasServiceClient<'a>) {
+    let env = Env::default();
+
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let contract_id = env.register(AxelarGasService, (&owner, &operator));
+    let client = AxelarGasServiceClient::new(&env, &contract_id);
+
+    (env, client)
+}
+
+#[test]
+fn migrate_accepts_gas_tokens() {
+    let (env, client) = setup_env();
+    let tokens = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    assert_eq!(
+        env.as_contract(&client.address, || {
+            <AxelarGasService as CustomMigratableInterface>::__migrate(&env, tokens.clone())
+        }),
+        Ok(())
+    );
+
+    assert_eq!(client.gas_tokens(), tokens);
+}
+
+#[test]
+fn migrate_fails_with_accepted_gas_token() {
+    let (env, client) = setup_env();
+    let token = Address::generate(&env);
+
+    client.mock_all_auths().add_gas_token(&token);
+
+    assert_err!(
+        env.as_contract(&client.address, || {
+            <AxelarGasService as CustomMigratableInterface>::__migrate(&env, vec![&env, token])
+        }),
+        ContractError::GasTokenAlreadyAccepted
+    );
+}
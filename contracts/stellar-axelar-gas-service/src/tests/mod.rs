@@ -1 +1,2 @@
+mod migrate;
 mod test;
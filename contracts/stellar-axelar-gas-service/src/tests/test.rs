@@ -3,15 +3,22 @@ extern crate std;
 
 use std::format;
 
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Address as _, Events};
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
-use soroban_sdk::{bytes, Address, Bytes, Env, String};
-use stellar_axelar_std::events::fmt_last_emitted_event;
+use soroban_sdk::{bytes, vec, Address, Env, String};
+use stellar_axelar_std::events::{fmt_last_emitted_event, Event};
 use stellar_axelar_std::types::Token;
 use stellar_axelar_std::{assert_auth, assert_auth_err, assert_contract_err, mock_auth};
 
 use crate::error::ContractError;
-use crate::event::{GasAddedEvent, GasCollectedEvent, GasPaidEvent, GasRefundedEvent};
+use crate::event::{
+    FeeDistributionSetEvent, GasAddedEvent, GasCollectedEvent, GasInfoUpdatedEvent, GasPaidEvent,
+    GasRefundedEvent, GasSettledEvent, GasTokenAddedEvent, GasTokenRemovedEvent,
+};
+use crate::types::{
+    FeeRecipient, GasInfo, GasMetadata, GasMetadataV1, GasPaymentId, PriorityTier,
+    GAS_PRICE_PRECISION,
+};
 use crate::{AxelarGasService, AxelarGasServiceClient};
 
 fn setup_env<'a>() -> (Env, Address, Address, AxelarGasServiceClient<'a>) {
@@ -25,8 +32,14 @@ fn setup_env<'a>() -> (Env, Address, Address, AxelarGasServiceClient<'a>) {
     (env, contract_id, operator, client)
 }
 
-fn setup_token(env: &Env, recipient: &Address, amount: i128) -> Token {
+fn setup_token(
+    env: &Env,
+    client: &AxelarGasServiceClient,
+    recipient: &Address,
+    amount: i128,
+) -> Token {
     let asset = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client.mock_all_auths().add_gas_token(&asset.address());
 
     StellarAssetClient::new(env, &asset.address())
         .mock_all_auths()
@@ -55,6 +68,41 @@ fn dummy_destination_data(env: &Env) -> (String, String) {
     (destination_chain, destination_address)
 }
 
+fn dummy_fee_distribution(env: &Env) -> soroban_sdk::Vec<FeeRecipient> {
+    vec![
+        env,
+        FeeRecipient {
+            receiver: Address::generate(env),
+            bps: 5000,
+        },
+        FeeRecipient {
+            receiver: Address::generate(env),
+            bps: 3000,
+        },
+        FeeRecipient {
+            receiver: Address::generate(env),
+            bps: 2000,
+        },
+    ]
+}
+
+fn accepted_gas_token(env: &Env, client: &AxelarGasServiceClient) -> Address {
+    let token = Address::generate(env);
+    client.mock_all_auths().add_gas_token(&token);
+
+    token
+}
+
+fn dummy_gas_info(token: &Address, min_gas_limit: Option<u64>) -> GasInfo {
+    GasInfo {
+        token: token.clone(),
+        base_fee: 100,
+        payload_byte_gas: 16,
+        gas_price: 2 * GAS_PRICE_PRECISION,
+        min_gas_limit,
+    }
+}
+
 #[test]
 fn register_gas_service() {
     let env = Env::default();
@@ -85,12 +133,13 @@ fn pay_gas_fails_with_zero_amount() {
     assert_contract_err!(
         client.mock_all_auths().try_pay_gas(
             &sender,
+            &message_id(&env),
             &destination_chain,
             &destination_address,
             &payload,
             &spender,
             &token,
-            &Bytes::new(&env),
+            &None,
         ),
         ContractError::InvalidAmount
     );
@@ -104,7 +153,7 @@ fn pay_gas_fails_with_insufficient_user_balance() {
     let spender: Address = Address::generate(&env);
     let sender: Address = Address::generate(&env);
     let gas_amount: i128 = 2;
-    let Token { address, .. } = setup_token(&env, &spender, gas_amount - 1);
+    let Token { address, .. } = setup_token(&env, &client, &spender, gas_amount - 1);
     let token = Token {
         address,
         amount: gas_amount,
@@ -124,24 +173,26 @@ fn pay_gas_fails_with_insufficient_user_balance() {
         spender,
         client.pay_gas(
             sender,
+            message_id(&env),
             destination_chain,
             destination_address,
             payload,
             spender,
             token,
-            Bytes::new(&env)
+            None::<GasMetadata>
         ),
-        &[(transfer_token_auth.invoke).clone()]
+        core::slice::from_ref(transfer_token_auth.invoke)
     );
 
     client.mock_auths(&[pay_gas_auth]).pay_gas(
         &sender,
+        &message_id(&env),
         &destination_chain,
         &destination_address,
         &payload,
         &spender,
         &token,
-        &Bytes::new(&env),
+        &None,
     );
 }
 
@@ -152,11 +203,17 @@ fn pay_gas() {
     let spender: Address = Address::generate(&env);
     let sender: Address = Address::generate(&env);
     let gas_amount: i128 = 1;
-    let token = setup_token(&env, &spender, gas_amount);
+    let token = setup_token(&env, &client, &spender, gas_amount);
     let token_client = TokenClient::new(&env, &token.address);
 
     let payload = bytes!(&env, 0x1234);
     let (destination_chain, destination_address) = dummy_destination_data(&env);
+    let metadata = Some(GasMetadata::V1(GasMetadataV1 {
+        refund_address: Some(Address::generate(&env)),
+        gas_limit: Some(100_000),
+        express: true,
+        priority: PriorityTier::Fast,
+    }));
 
     let transfer_token_auth = mock_auth!(
         env,
@@ -169,24 +226,26 @@ fn pay_gas() {
         spender,
         client.pay_gas(
             sender,
+            message_id(&env),
             destination_chain,
             destination_address,
             payload,
             spender,
             token,
-            Bytes::new(&env)
+            metadata
         ),
-        &[(transfer_token_auth.invoke).clone()]
+        core::slice::from_ref(transfer_token_auth.invoke)
     );
 
     client.mock_auths(&[pay_gas_auth]).pay_gas(
         &sender,
+        &message_id(&env),
         &destination_chain,
         &destination_address,
         &payload,
         &spender,
         &token,
-        &Bytes::new(&env),
+        &metadata,
     );
 
     goldie::assert!(fmt_last_emitted_event::<GasPaidEvent>(&env));
@@ -225,7 +284,7 @@ fn add_gas_fails_with_insufficient_user_balance() {
     let sender: Address = Address::generate(&env);
     let message_id = message_id(&env);
     let gas_amount: i128 = 2;
-    let Token { address, .. } = setup_token(&env, &spender, gas_amount - 1);
+    let Token { address, .. } = setup_token(&env, &client, &spender, gas_amount - 1);
     let token = Token {
         address,
         amount: gas_amount,
@@ -242,7 +301,7 @@ fn add_gas() {
     let spender: Address = Address::generate(&env);
     let sender: Address = Address::generate(&env);
     let gas_amount: i128 = 1;
-    let token = setup_token(&env, &spender, gas_amount);
+    let token = setup_token(&env, &client, &spender, gas_amount);
     let token_client = TokenClient::new(&env, &token.address);
 
     let message_id = message_id(&env);
@@ -263,7 +322,7 @@ fn collect_fees_fails_with_zero_amount() {
     let refund_amount = 0;
     let supply: i128 = 1000;
 
-    let Token { address, .. } = setup_token(&env, &spender, supply);
+    let Token { address, .. } = setup_token(&env, &client, &spender, supply);
     let token = Token {
         address,
         amount: refund_amount,
@@ -297,6 +356,24 @@ fn collect_fees_fails_with_insufficient_balance() {
     );
 }
 
+#[test]
+fn collect_fees_fails_with_refundable_gas() {
+    let (env, _, operator, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1000);
+
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id(&env), &spender, &token);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_collect_fees(&operator, &token),
+        ContractError::InsufficientBalance
+    );
+}
+
 #[test]
 fn collect_fees_fails_without_authorization() {
     let (env, contract_id, _, client) = setup_env();
@@ -346,7 +423,7 @@ fn collect_fees_succeeds() {
         env,
         operator,
         client.collect_fees(&operator, &token),
-        &[(transfer_token_auth.invoke).clone()]
+        core::slice::from_ref(transfer_token_auth.invoke)
     );
 
     client
@@ -384,69 +461,1186 @@ fn refund_fails_without_authorization() {
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #10)")] // "balance is not sufficient to spend"
 fn refund_fails_with_insufficient_balance() {
-    let (env, contract_id, operator, client) = setup_env();
+    let (env, contract_id, _, client) = setup_env();
 
-    let supply: i128 = 1;
-    let asset = &env.register_stellar_asset_contract_v2(Address::generate(&env));
-    StellarAssetClient::new(&env, &asset.address())
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let message_id = message_id(&env);
+
+    client
         .mock_all_auths()
-        .mint(&contract_id, &supply);
+        .add_gas(&sender, &message_id, &spender, &token);
+    TokenClient::new(&env, &token.address)
+        .mock_all_auths()
+        .transfer(&contract_id, &Address::generate(&env), &token.amount);
+    assert_eq!(
+        0,
+        TokenClient::new(&env, &token.address).balance(&contract_id)
+    );
 
-    let receiver: Address = Address::generate(&env);
-    let refund_amount: i128 = 2;
+    client
+        .mock_all_auths()
+        .refund(&message_id, &spender, &token);
+}
+
+#[test]
+fn refund_succeeds() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let gas_amount: i128 = 1000;
+    let Token { address, .. } = setup_token(&env, &client, &spender, gas_amount);
+    let token_client = TokenClient::new(&env, &address);
+    let message_id = message_id(&env);
+
+    client.mock_all_auths().add_gas(
+        &sender,
+        &message_id,
+        &spender,
+        &Token {
+            address: address.clone(),
+            amount: gas_amount,
+        },
+    );
+
+    let refund_amount: i128 = 1;
     let token = Token {
-        address: asset.address(),
+        address,
         amount: refund_amount,
     };
 
+    assert_auth!(
+        client.operator(),
+        client.refund(&message_id, &spender, &token)
+    );
+
+    goldie::assert!(fmt_last_emitted_event::<GasRefundedEvent>(&env));
+
+    assert_eq!(refund_amount, token_client.balance(&spender));
+    assert_eq!(
+        gas_amount - refund_amount,
+        token_client.balance(&contract_id)
+    );
+    assert_eq!(
+        client.gas_paid(&message_id, &spender).amount,
+        gas_amount - refund_amount
+    );
+}
+
+#[test]
+fn refund_succeeds_for_pay_gas() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let token_client = TokenClient::new(&env, &token.address);
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+
+    client.mock_all_auths().pay_gas(
+        &sender,
+        &message_id(&env),
+        &destination_chain,
+        &destination_address,
+        &payload,
+        &spender,
+        &token,
+        &None,
+    );
+
     let message_id = message_id(&env);
+    assert_eq!(client.gas_paid(&message_id, &spender), token);
 
-    let transfer_token_auth = mock_auth!(
-        env,
-        operator,
-        token.transfer(operator, client.address, token.amount)
+    client
+        .mock_all_auths()
+        .refund(&message_id, &spender, &token);
+
+    assert_eq!(token.amount, token_client.balance(&spender));
+    assert_eq!(0, token_client.balance(&contract_id));
+}
+
+#[test]
+fn add_gas_accumulates_gas_paid() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let other_spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let gas_amount: i128 = 1;
+    let token = setup_token(&env, &client, &spender, 2 * gas_amount);
+    StellarAssetClient::new(&env, &token.address)
+        .mock_all_auths()
+        .mint(&other_spender, &gas_amount);
+    let token = Token {
+        address: token.address,
+        amount: gas_amount,
+    };
+    let message_id = message_id(&env);
+
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &other_spender, &token);
+
+    assert_eq!(
+        client.gas_paid(&message_id, &spender),
+        Token {
+            address: token.address.clone(),
+            amount: 2 * gas_amount,
+        }
     );
+    assert_eq!(client.gas_paid(&message_id, &other_spender), token);
+}
 
-    let refund_auth = mock_auth!(
-        env,
-        operator,
-        client.refund(&message_id, &receiver, &token),
-        &[(transfer_token_auth.invoke).clone()]
+#[test]
+fn add_gas_fails_with_different_token() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let other_token = setup_token(&env, &client, &spender, 1);
+    let message_id = message_id(&env);
+
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_add_gas(&sender, &message_id, &spender, &other_token),
+        ContractError::UnsupportedGasToken
     );
+}
+
+#[test]
+fn add_gas_with_different_token_by_other_spender() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let other_spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let other_token = setup_token(&env, &client, &other_spender, 1);
+    let message_id = message_id(&env);
 
     client
-        .mock_auths(&[refund_auth])
-        .refund(&message_id, &receiver, &token)
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &other_spender, &other_token);
+
+    assert_eq!(client.gas_paid(&message_id, &spender), token);
+    assert_eq!(client.gas_paid(&message_id, &other_spender), other_token);
 }
 
 #[test]
-fn refund_succeeds() {
+fn gas_paid_fails_if_not_paid() {
+    let (env, _, _, client) = setup_env();
+
+    assert_contract_err!(
+        client.try_gas_paid(&message_id(&env), &Address::generate(&env)),
+        ContractError::GasPaymentNotFound
+    );
+}
+
+#[test]
+fn refund_fails_if_not_paid() {
     let (env, contract_id, _, client) = setup_env();
 
-    let supply: i128 = 1000;
-    let asset = &env.register_stellar_asset_contract_v2(Address::generate(&env));
-    StellarAssetClient::new(&env, &asset.address())
+    let token = setup_token(&env, &client, &contract_id, 1);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_refund(&message_id(&env), &Address::generate(&env), &token),
+        ContractError::GasPaymentNotFound
+    );
+}
+
+#[test]
+fn refund_fails_with_other_refund_address() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let message_id = message_id(&env);
+
+    client
         .mock_all_auths()
-        .mint(&contract_id, &supply);
+        .add_gas(&sender, &message_id, &spender, &token);
 
-    let token_client = TokenClient::new(&env, &asset.address());
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_refund(&message_id, &Address::generate(&env), &token),
+        ContractError::GasPaymentNotFound
+    );
+}
 
-    let receiver: Address = Address::generate(&env);
-    let refund_amount: i128 = 1;
-    let token = Token {
-        address: asset.address(),
-        amount: refund_amount,
-    };
+#[test]
+fn settle_gas_payments() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let other_spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1000);
+    StellarAssetClient::new(&env, &token.address)
+        .mock_all_auths()
+        .mint(&other_spender, &token.amount);
+    let message_id = message_id(&env);
+    let other_message_id = String::from_str(&env, "other");
+
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &other_message_id, &other_spender, &token);
+
+    let payments = vec![
+        &env,
+        GasPaymentId {
+            message_id: message_id.clone(),
+            refund_address: spender.clone(),
+        },
+        GasPaymentId {
+            message_id: other_message_id.clone(),
+            refund_address: other_spender.clone(),
+        },
+    ];
+
+    assert_auth!(client.operator(), client.settle_gas_payments(&payments));
+
+    goldie::assert!(fmt_last_emitted_event::<GasSettledEvent>(&env));
+
+    for (message_id, refund_address) in [(message_id, spender), (other_message_id, other_spender)] {
+        assert_contract_err!(
+            client.try_gas_paid(&message_id, &refund_address),
+            ContractError::GasPaymentNotFound
+        );
+        assert_contract_err!(
+            client
+                .mock_all_auths()
+                .try_refund(&message_id, &refund_address, &token),
+            ContractError::GasPaymentNotFound
+        );
+    }
+}
+
+#[test]
+fn settle_gas_payments_fails_if_not_paid() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1000);
+    let message_id = message_id(&env);
+
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+
+    let payments = vec![
+        &env,
+        GasPaymentId {
+            message_id: message_id.clone(),
+            refund_address: spender.clone(),
+        },
+        GasPaymentId {
+            message_id: message_id.clone(),
+            refund_address: Address::generate(&env),
+        },
+    ];
+
+    assert_contract_err!(
+        client.mock_all_auths().try_settle_gas_payments(&payments),
+        ContractError::GasPaymentNotFound
+    );
+    assert_eq!(client.gas_paid(&message_id, &spender), token);
+}
+
+#[test]
+fn settle_gas_payments_fails_without_authorization() {
+    let (env, _, _, client) = setup_env();
+
+    let user: Address = Address::generate(&env);
+    let payments = vec![
+        &env,
+        GasPaymentId {
+            message_id: message_id(&env),
+            refund_address: user.clone(),
+        },
+    ];
+
+    assert_auth_err!(user, client.settle_gas_payments(&payments));
+}
 
+#[test]
+fn refund_fails_if_exceeding_gas_paid() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
     let message_id = message_id(&env);
 
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+    client
+        .mock_all_auths()
+        .refund(&message_id, &spender, &token);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_refund(&message_id, &spender, &token),
+        ContractError::RefundExceedsGasPaid
+    );
+}
+
+#[test]
+fn set_gas_info() {
+    let (env, _, _, client) = setup_env();
+
+    let (destination_chain, _) = dummy_destination_data(&env);
+    let gas_info = dummy_gas_info(&accepted_gas_token(&env, &client), None);
+
     assert_auth!(
         client.operator(),
-        client.refund(&message_id, &receiver, &token)
+        client.set_gas_info(&destination_chain, &gas_info)
     );
 
-    goldie::assert!(fmt_last_emitted_event::<GasRefundedEvent>(&env));
+    goldie::assert!(fmt_last_emitted_event::<GasInfoUpdatedEvent>(&env));
 
-    assert_eq!(refund_amount, token_client.balance(&receiver));
-    assert_eq!(supply - refund_amount, token_client.balance(&contract_id));
+    assert_eq!(client.gas_info(&destination_chain), gas_info);
+}
+
+#[test]
+fn set_gas_info_fails_without_authorization() {
+    let (env, _, _, client) = setup_env();
+
+    let (destination_chain, _) = dummy_destination_data(&env);
+    let gas_info = dummy_gas_info(&Address::generate(&env), None);
+
+    assert_auth_err!(
+        Address::generate(&env),
+        client.set_gas_info(&destination_chain, &gas_info)
+    );
+}
+
+#[test]
+fn set_gas_info_fails_with_negative_fee() {
+    let (env, _, _, client) = setup_env();
+
+    let (destination_chain, _) = dummy_destination_data(&env);
+    let gas_info = GasInfo {
+        base_fee: -1,
+        ..dummy_gas_info(&Address::generate(&env), None)
+    };
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_gas_info(&destination_chain, &gas_info),
+        ContractError::InvalidGasInfo
+    );
+}
+
+#[test]
+fn set_gas_info_fails_with_unaccepted_token() {
+    let (env, _, _, client) = setup_env();
+
+    let (destination_chain, _) = dummy_destination_data(&env);
+    let gas_info = dummy_gas_info(&Address::generate(&env), None);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_gas_info(&destination_chain, &gas_info),
+        ContractError::GasTokenNotAccepted
+    );
+}
+
+#[test]
+fn gas_info_fails_if_not_set() {
+    let (env, _, _, client) = setup_env();
+
+    let (destination_chain, _) = dummy_destination_data(&env);
+
+    assert_contract_err!(
+        client.try_gas_info(&destination_chain),
+        ContractError::GasInfoNotFound
+    );
+}
+
+#[test]
+fn estimate_gas_fee() {
+    let (env, _, _, client) = setup_env();
+
+    let (destination_chain, _) = dummy_destination_data(&env);
+    let token = accepted_gas_token(&env, &client);
+    let gas_info = dummy_gas_info(&token, None);
+    let payload_len: u32 = 10;
+    let gas_limit: u64 = 1000;
+
+    client
+        .mock_all_auths()
+        .set_gas_info(&destination_chain, &gas_info);
+
+    // 100 + (1000 + 10 * 16) * 2
+    assert_eq!(
+        client.estimate_gas_fee(&destination_chain, &payload_len, &gas_limit, &token),
+        2420
+    );
+}
+
+#[test]
+fn estimate_gas_fee_fails_with_unsupported_token() {
+    let (env, _, _, client) = setup_env();
+
+    let (destination_chain, _) = dummy_destination_data(&env);
+    let gas_info = dummy_gas_info(&accepted_gas_token(&env, &client), None);
+    let payload_len: u32 = 10;
+    let gas_limit: u64 = 1000;
+
+    client
+        .mock_all_auths()
+        .set_gas_info(&destination_chain, &gas_info);
+
+    assert_contract_err!(
+        client.try_estimate_gas_fee(
+            &destination_chain,
+            &payload_len,
+            &gas_limit,
+            &Address::generate(&env)
+        ),
+        ContractError::UnsupportedGasToken
+    );
+}
+
+#[test]
+fn estimate_gas_fee_fails_on_overflow() {
+    let (env, _, _, client) = setup_env();
+
+    let (destination_chain, _) = dummy_destination_data(&env);
+    let token = accepted_gas_token(&env, &client);
+    let gas_info = GasInfo {
+        gas_price: i128::MAX,
+        ..dummy_gas_info(&token, None)
+    };
+    let payload_len: u32 = 10;
+    let gas_limit: u64 = 1000;
+
+    client
+        .mock_all_auths()
+        .set_gas_info(&destination_chain, &gas_info);
+
+    assert_contract_err!(
+        client.try_estimate_gas_fee(&destination_chain, &payload_len, &gas_limit, &token),
+        ContractError::GasFeeOverflow
+    );
+}
+
+#[test]
+fn pay_gas_enforces_min_fee() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+    let gas_limit: u64 = 1000;
+    // 100 + (1000 + 2 * 16) * 2
+    let min_fee: i128 = 2164;
+    let token = setup_token(&env, &client, &spender, min_fee);
+    let token_client = TokenClient::new(&env, &token.address);
+
+    client.mock_all_auths().set_gas_info(
+        &destination_chain,
+        &dummy_gas_info(&token.address, Some(gas_limit)),
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_pay_gas(
+            &sender,
+            &message_id(&env),
+            &destination_chain,
+            &destination_address,
+            &payload,
+            &spender,
+            &Token {
+                address: token.address.clone(),
+                amount: min_fee - 1,
+            },
+            &None,
+        ),
+        ContractError::InsufficientGasPayment
+    );
+
+    client.mock_all_auths().pay_gas(
+        &sender,
+        &message_id(&env),
+        &destination_chain,
+        &destination_address,
+        &payload,
+        &spender,
+        &token,
+        &None,
+    );
+
+    assert_eq!(min_fee, token_client.balance(&contract_id));
+}
+
+#[test]
+fn pay_gas_fails_with_unsupported_token_when_min_fee_enforced() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+    let gas_limit: u64 = 1000;
+    let token = setup_token(&env, &client, &spender, 10_000);
+
+    client.mock_all_auths().set_gas_info(
+        &destination_chain,
+        &dummy_gas_info(&accepted_gas_token(&env, &client), Some(gas_limit)),
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_pay_gas(
+            &sender,
+            &message_id(&env),
+            &destination_chain,
+            &destination_address,
+            &payload,
+            &spender,
+            &token,
+            &None,
+        ),
+        ContractError::UnsupportedGasToken
+    );
+}
+
+#[test]
+fn add_gas_token() {
+    let (env, _, _, client) = setup_env();
+
+    let token = Address::generate(&env);
+
+    assert_auth!(client.operator(), client.add_gas_token(&token));
+
+    goldie::assert!(fmt_last_emitted_event::<GasTokenAddedEvent>(&env));
+
+    assert_eq!(client.gas_tokens(), vec![&env, token]);
+}
+
+#[test]
+fn add_gas_token_fails_if_already_accepted() {
+    let (env, _, _, client) = setup_env();
+
+    let token = Address::generate(&env);
+    client.mock_all_auths().add_gas_token(&token);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_add_gas_token(&token),
+        ContractError::GasTokenAlreadyAccepted
+    );
+}
+
+#[test]
+fn gas_token_management_fails_without_authorization() {
+    let (env, _, _, client) = setup_env();
+
+    let token = Address::generate(&env);
+    let user: Address = Address::generate(&env);
+
+    assert_auth_err!(user, client.add_gas_token(&token));
+    assert_auth_err!(user, client.remove_gas_token(&token));
+}
+
+#[test]
+fn remove_gas_token() {
+    let (env, _, _, client) = setup_env();
+
+    let token = Address::generate(&env);
+    let other_token = Address::generate(&env);
+    client.mock_all_auths().add_gas_token(&token);
+    client.mock_all_auths().add_gas_token(&other_token);
+
+    assert_auth!(client.operator(), client.remove_gas_token(&token));
+
+    goldie::assert!(fmt_last_emitted_event::<GasTokenRemovedEvent>(&env));
+
+    assert_eq!(client.gas_tokens(), vec![&env, other_token]);
+}
+
+#[test]
+fn remove_gas_token_fails_if_not_accepted() {
+    let (env, _, _, client) = setup_env();
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_remove_gas_token(&Address::generate(&env)),
+        ContractError::GasTokenNotAccepted
+    );
+}
+
+#[test]
+fn pay_gas_fails_with_unaccepted_token() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+
+    client.mock_all_auths().remove_gas_token(&token.address);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_pay_gas(
+            &sender,
+            &message_id(&env),
+            &destination_chain,
+            &destination_address,
+            &payload,
+            &spender,
+            &token,
+            &None,
+        ),
+        ContractError::GasTokenNotAccepted
+    );
+}
+
+#[test]
+fn add_gas_fails_with_unaccepted_token() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+
+    client.mock_all_auths().remove_gas_token(&token.address);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_add_gas(&sender, &message_id(&env), &spender, &token),
+        ContractError::GasTokenNotAccepted
+    );
+}
+
+#[test]
+fn set_fee_distribution() {
+    let (env, _, _, client) = setup_env();
+
+    let recipients = dummy_fee_distribution(&env);
+
+    assert_auth!(client.owner(), client.set_fee_distribution(&recipients));
+
+    goldie::assert!(fmt_last_emitted_event::<FeeDistributionSetEvent>(&env));
+
+    assert_eq!(client.fee_distribution(), recipients);
+}
+
+#[test]
+fn set_fee_distribution_fails_without_authorization() {
+    let (env, _, operator, client) = setup_env();
+
+    let recipients = dummy_fee_distribution(&env);
+
+    assert_auth_err!(operator, client.set_fee_distribution(&recipients));
+}
+
+#[test]
+fn set_fee_distribution_fails_with_invalid_shares() {
+    let (env, _, _, client) = setup_env();
+
+    let receiver = Address::generate(&env);
+    let invalid_distributions = [
+        vec![&env],
+        vec![
+            &env,
+            FeeRecipient {
+                receiver: receiver.clone(),
+                bps: 9999,
+            },
+        ],
+        vec![
+            &env,
+            FeeRecipient {
+                receiver: receiver.clone(),
+                bps: 10_000,
+            },
+            FeeRecipient {
+                receiver: receiver.clone(),
+                bps: 0,
+            },
+        ],
+        vec![
+            &env,
+            FeeRecipient {
+                receiver: receiver.clone(),
+                bps: u32::MAX,
+            },
+            FeeRecipient { receiver, bps: 1 },
+        ],
+    ];
+
+    for recipients in invalid_distributions {
+        assert_contract_err!(
+            client
+                .mock_all_auths()
+                .try_set_fee_distribution(&recipients),
+            ContractError::InvalidFeeDistribution
+        );
+    }
+}
+
+#[test]
+fn distribute_fees() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let recipients = dummy_fee_distribution(&env);
+    let token = setup_token(&env, &client, &contract_id, 1001);
+    let other_token = setup_token(&env, &client, &contract_id, 10);
+    let empty_token = setup_token(&env, &client, &contract_id, 0);
+
+    let tokens = vec![
+        &env,
+        token.address.clone(),
+        other_token.address.clone(),
+        empty_token.address,
+    ];
+
+    client.mock_all_auths().set_fee_distribution(&recipients);
+
+    assert_auth!(client.operator(), client.distribute_fees(&tokens));
+
+    goldie::assert!(fmt_last_emitted_event::<GasCollectedEvent>(&env));
+
+    let expected_amounts = [(&token, [500, 300, 201]), (&other_token, [5, 3, 2])];
+    for (token, amounts) in expected_amounts {
+        let token_client = TokenClient::new(&env, &token.address);
+
+        for (recipient, amount) in recipients.iter().zip(amounts) {
+            assert_eq!(token_client.balance(&recipient.receiver), amount);
+        }
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+}
+
+#[test]
+fn distribute_fees_excludes_refundable_gas() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let recipients = dummy_fee_distribution(&env);
+    let token = setup_token(&env, &client, &spender, 1000);
+    let token_client = TokenClient::new(&env, &token.address);
+    let fees: i128 = 10;
+    StellarAssetClient::new(&env, &token.address)
+        .mock_all_auths()
+        .mint(&contract_id, &fees);
+
+    let message_id = message_id(&env);
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+
+    client.mock_all_auths().set_fee_distribution(&recipients);
+    client
+        .mock_all_auths()
+        .distribute_fees(&vec![&env, token.address.clone()]);
+
+    for (recipient, amount) in recipients.iter().zip([5, 3, 2]) {
+        assert_eq!(token_client.balance(&recipient.receiver), amount);
+    }
+    assert_eq!(token_client.balance(&contract_id), token.amount);
+
+    client
+        .mock_all_auths()
+        .refund(&message_id, &spender, &token);
+
+    assert_eq!(token_client.balance(&spender), token.amount);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn distribute_fees_includes_settled_gas() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let recipients = dummy_fee_distribution(&env);
+    let token = setup_token(&env, &client, &spender, 10);
+    let token_client = TokenClient::new(&env, &token.address);
+
+    let message_id = message_id(&env);
+    client
+        .mock_all_auths()
+        .add_gas(&sender, &message_id, &spender, &token);
+    client.mock_all_auths().settle_gas_payments(&vec![
+        &env,
+        GasPaymentId {
+            message_id,
+            refund_address: spender,
+        },
+    ]);
+
+    client.mock_all_auths().set_fee_distribution(&recipients);
+    client
+        .mock_all_auths()
+        .distribute_fees(&vec![&env, token.address.clone()]);
+
+    for (recipient, amount) in recipients.iter().zip([5, 3, 2]) {
+        assert_eq!(token_client.balance(&recipient.receiver), amount);
+    }
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn distribute_fees_fails_without_fee_distribution() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let token = setup_token(&env, &client, &contract_id, 1);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_distribute_fees(&vec![&env, token.address]),
+        ContractError::FeeDistributionNotSet
+    );
+}
+
+#[test]
+fn distribute_fees_fails_without_authorization() {
+    let (env, _, _, client) = setup_env();
+
+    let user: Address = Address::generate(&env);
+    let tokens = vec![&env, Address::generate(&env)];
+
+    assert_auth_err!(user, client.distribute_fees(&tokens));
+}
+
+#[test]
+fn pay_gas_without_metadata_refunds_to_spender() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+
+    client.mock_all_auths().pay_gas(
+        &sender,
+        &message_id(&env),
+        &destination_chain,
+        &destination_address,
+        &payload,
+        &spender,
+        &token,
+        &None,
+    );
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let event = GasPaidEvent::from_event(&env, topics, data);
+    assert_eq!(event.refund_address, spender);
+    assert_eq!(event.gas_limit, None);
+    assert!(!event.express);
+    assert_eq!(event.priority, PriorityTier::Standard);
+}
+
+#[test]
+fn pay_gas_with_refund_address_refunds_to_refund_address() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let refund_address: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+    let metadata = Some(GasMetadata::V1(GasMetadataV1 {
+        refund_address: Some(refund_address.clone()),
+        gas_limit: None,
+        express: false,
+        priority: PriorityTier::Standard,
+    }));
+
+    client.mock_all_auths().pay_gas(
+        &sender,
+        &message_id(&env),
+        &destination_chain,
+        &destination_address,
+        &payload,
+        &spender,
+        &token,
+        &metadata,
+    );
+
+    let message_id = message_id(&env);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_refund(&message_id, &spender, &token),
+        ContractError::GasPaymentNotFound
+    );
+
+    client
+        .mock_all_auths()
+        .refund(&message_id, &refund_address, &token);
+
+    assert_eq!(
+        token.amount,
+        TokenClient::new(&env, &token.address).balance(&refund_address)
+    );
+}
+
+#[test]
+fn pay_gas_fails_with_zero_gas_limit() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &spender, 1);
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+    let metadata = Some(GasMetadata::V1(GasMetadataV1 {
+        refund_address: None,
+        gas_limit: Some(0),
+        express: false,
+        priority: PriorityTier::Standard,
+    }));
+
+    assert_contract_err!(
+        client.mock_all_auths().try_pay_gas(
+            &sender,
+            &message_id(&env),
+            &destination_chain,
+            &destination_address,
+            &payload,
+            &spender,
+            &token,
+            &metadata,
+        ),
+        ContractError::InvalidGasMetadata
+    );
+}
+
+#[test]
+fn pay_gas_min_fee_covers_metadata_gas_limit() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+    // 100 + (1000 + 2 * 16) * 2
+    let min_fee: i128 = 2164;
+    let token = setup_token(&env, &client, &spender, min_fee);
+    let metadata = Some(GasMetadata::V1(GasMetadataV1 {
+        refund_address: None,
+        gas_limit: Some(2000),
+        express: false,
+        priority: PriorityTier::Standard,
+    }));
+
+    client.mock_all_auths().set_gas_info(
+        &destination_chain,
+        &dummy_gas_info(&token.address, Some(1000)),
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_pay_gas(
+            &sender,
+            &message_id(&env),
+            &destination_chain,
+            &destination_address,
+            &payload,
+            &spender,
+            &token,
+            &metadata,
+        ),
+        ContractError::InsufficientGasPayment
+    );
+}
+
+fn approve_gas_allowance(env: &Env, token: &Token, from: &Address, spender: &Address) {
+    TokenClient::new(env, &token.address)
+        .mock_all_auths()
+        .approve(
+            from,
+            spender,
+            &token.amount,
+            &(env.ledger().sequence() + 100),
+        );
+}
+
+#[test]
+fn pay_gas_from() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let from: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let gas_amount: i128 = 1;
+    let token = setup_token(&env, &client, &from, gas_amount);
+    let token_client = TokenClient::new(&env, &token.address);
+    approve_gas_allowance(&env, &token, &from, &spender);
+
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+    let metadata: Option<GasMetadata> = None;
+
+    let transfer_token_auth = mock_auth!(
+        env,
+        spender,
+        token.transfer_from(spender, from, client.address, token.amount)
+    );
+
+    let pay_gas_auth = mock_auth!(
+        env,
+        spender,
+        client.pay_gas_from(
+            sender,
+            message_id(&env),
+            destination_chain,
+            destination_address,
+            payload,
+            spender,
+            from,
+            token,
+            metadata
+        ),
+        core::slice::from_ref(transfer_token_auth.invoke)
+    );
+
+    client.mock_auths(&[pay_gas_auth]).pay_gas_from(
+        &sender,
+        &message_id(&env),
+        &destination_chain,
+        &destination_address,
+        &payload,
+        &spender,
+        &from,
+        &token,
+        &metadata,
+    );
+
+    goldie::assert!(fmt_last_emitted_event::<GasPaidEvent>(&env));
+
+    assert_eq!(0, token_client.balance(&from));
+    assert_eq!(0, token_client.allowance(&from, &spender));
+    assert_eq!(gas_amount, token_client.balance(&contract_id));
+}
+
+#[test]
+fn pay_gas_from_fails_without_spender_authorization() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let from: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let token = setup_token(&env, &client, &from, 1);
+    approve_gas_allowance(&env, &token, &from, &spender);
+
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+    let metadata: Option<GasMetadata> = None;
+
+    assert_auth_err!(
+        from,
+        client.pay_gas_from(
+            &sender,
+            &message_id(&env),
+            &destination_chain,
+            &destination_address,
+            &payload,
+            &spender,
+            &from,
+            &token,
+            &metadata,
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")] // "allowance is not sufficient to spend"
+fn pay_gas_from_fails_with_insufficient_allowance() {
+    let (env, _, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let from: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let gas_amount: i128 = 2;
+    let token = setup_token(&env, &client, &from, gas_amount);
+    approve_gas_allowance(
+        &env,
+        &Token {
+            address: token.address.clone(),
+            amount: gas_amount - 1,
+        },
+        &from,
+        &spender,
+    );
+
+    let payload = bytes!(&env, 0x1234);
+    let (destination_chain, destination_address) = dummy_destination_data(&env);
+
+    client.mock_all_auths().pay_gas_from(
+        &sender,
+        &message_id(&env),
+        &destination_chain,
+        &destination_address,
+        &payload,
+        &spender,
+        &from,
+        &token,
+        &None,
+    );
+}
+
+#[test]
+fn add_gas_from() {
+    let (env, contract_id, _, client) = setup_env();
+
+    let spender: Address = Address::generate(&env);
+    let from: Address = Address::generate(&env);
+    let sender: Address = Address::generate(&env);
+    let gas_amount: i128 = 1;
+    let token = setup_token(&env, &client, &from, gas_amount);
+    let token_client = TokenClient::new(&env, &token.address);
+    approve_gas_allowance(&env, &token, &from, &spender);
+
+    let message_id = message_id(&env);
+    client
+        .mock_all_auths()
+        .add_gas_from(&sender, &message_id, &spender, &from, &token);
+
+    goldie::assert!(fmt_last_emitted_event::<GasAddedEvent>(&env));
+
+    assert_eq!(0, token_client.balance(&from));
+    assert_eq!(0, token_client.allowance(&from, &spender));
+    assert_eq!(gas_amount, token_client.balance(&contract_id));
+    assert_eq!(client.gas_paid(&message_id, &from), token);
 }
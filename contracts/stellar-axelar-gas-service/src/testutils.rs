@@ -14,8 +14,12 @@ pub fn setup_gas_service<'a>(env: &Env) -> AxelarGasServiceClient<'a> {
     gas_service_client
 }
 
-pub fn setup_gas_token(env: &Env, sender: &Address) -> Token {
+/// Registers a gas token that is accepted by `gas_service`, and mints the returned gas amount to `sender`.
+///
+/// The `gas_service` must be passed in to accept the token, since only accepted tokens can be used to pay gas.
+pub fn setup_gas_token(env: &Env, gas_service: &AxelarGasServiceClient, sender: &Address) -> Token {
     let asset = &env.register_stellar_asset_contract_v2(Address::generate(env));
+    gas_service.mock_all_auths().add_gas_token(&asset.address());
     let gas_amount: i128 = 1;
     let gas_token = Token {
         address: asset.address(),
@@ -0,0 +1,70 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// The total of the basis points of a fee distribution.
+pub const TOTAL_FEE_BPS: u32 = 10_000;
+
+/// The precision of [`GasInfo::gas_price`].
+pub const GAS_PRICE_PRECISION: i128 = 1_000_000_000_000_000_000;
+
+/// Gas pricing of a destination chain, used to estimate the fee of a cross-chain message.
+///
+/// The fee of a message is `base_fee + (gas_limit + payload_len * payload_byte_gas) * gas_price / GAS_PRICE_PRECISION`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasInfo {
+    /// The token that gas for the destination chain is priced in. It must be an accepted gas token.
+    pub token: Address,
+    /// The fixed fee of a message, in `token` units.
+    pub base_fee: i128,
+    /// The destination chain gas spent per byte of payload.
+    pub payload_byte_gas: u64,
+    /// The price of a destination chain gas unit in `token` units, scaled by [`GAS_PRICE_PRECISION`].
+    pub gas_price: i128,
+    /// The gas limit used to enforce a minimum fee in `pay_gas`, if any.
+    pub min_gas_limit: Option<u64>,
+}
+
+/// A gas payment for a cross-chain message, identified by the message id and the address it's refundable to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasPaymentId {
+    pub message_id: String,
+    pub refund_address: Address,
+}
+
+/// A receiver of a share of the collected fees.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecipient {
+    pub receiver: Address,
+    /// The share of the fees in basis points, out of [`TOTAL_FEE_BPS`].
+    pub bps: u32,
+}
+
+/// The delivery priority requested for a cross-chain message.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PriorityTier {
+    Standard,
+    Fast,
+    Urgent,
+}
+
+/// Versioned metadata of a gas payment, describing how relayers should deliver the message.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GasMetadata {
+    V1(GasMetadataV1),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasMetadataV1 {
+    /// The address that excess gas is refunded to. Defaults to the address paying for the gas if not set.
+    pub refund_address: Option<Address>,
+    /// The gas limit of executing the message on the destination chain. Estimated by relayers if not set.
+    pub gas_limit: Option<u64>,
+    /// Whether the message should be delivered with express execution.
+    pub express: bool,
+    pub priority: PriorityTier,
+}
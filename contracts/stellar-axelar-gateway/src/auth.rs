@@ -1,11 +1,36 @@
-use soroban_sdk::{Bytes, BytesN, Env, Vec};
+use soroban_sdk::crypto::bls12_381::{G1Affine, G2Affine};
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::{vec, Bytes, BytesN, Env, Vec};
 use stellar_axelar_std::ensure;
 use stellar_axelar_std::events::Event;
+use stellar_axelar_std::ttl::{PERSISTENT_TTL_EXTEND_TO, PERSISTENT_TTL_THRESHOLD};
 
 use crate::error::ContractError;
-use crate::event::SignersRotatedEvent;
+use crate::event::{
+    BlsPublicKeyRegisteredEvent, SignersProposalCancelledEvent, SignersProposedEvent,
+    SignersPrunedEvent, SignersRotatedEvent,
+};
 use crate::storage;
-use crate::types::{Proof, ProofSignature, ProofSigner, WeightedSigner, WeightedSigners};
+use crate::types::{
+    AggregateProof, PendingSigners, Proof, ProofSignature, ProofSigner, SignaturesProof,
+    SignerPublicKey, WeightedSigner, WeightedSigners,
+};
+
+/// Domain separation tag for hashing messages to G2, following the BLS proof of possession ciphersuite with public keys in G1.
+const BLS_SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Domain separation tag for hashing public keys to G2 when proving possession of their secret key.
+const BLS_POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Negation of the standard BLS12-381 G1 generator, in uncompressed form.
+const BLS_G1_GENERATOR_NEG: [u8; 96] = [
+    0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c, 0x4f, 0xa9, 0xac, 0x0f,
+    0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05, 0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58,
+    0x6c, 0x55, 0xe8, 0x3f, 0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+    0x11, 0x4d, 0x1d, 0x68, 0x55, 0xd5, 0x45, 0xa8, 0xaa, 0x7d, 0x76, 0xc8, 0xcf, 0x2e, 0x21, 0xf2,
+    0x67, 0x81, 0x6a, 0xef, 0x1d, 0xb5, 0x07, 0xc9, 0x66, 0x55, 0xb9, 0xd5, 0xca, 0xac, 0x42, 0x36,
+    0x4e, 0x6f, 0x38, 0xba, 0x0e, 0xcb, 0x75, 0x1b, 0xad, 0x54, 0xdc, 0xd6, 0xb9, 0x39, 0xc2, 0xca,
+];
 
 pub fn initialize_auth(
     env: Env,
@@ -33,9 +58,7 @@ pub fn validate_proof(
     data_hash: &BytesN<32>,
     proof: Proof,
 ) -> Result<bool, ContractError> {
-    let signers_set = proof.weighted_signers();
-
-    let signers_hash = signers_set.hash(env);
+    let signers_hash = proof.signers_hash(env);
 
     let signers_epoch = storage::try_epoch_by_signers_hash(env, signers_hash.clone())
         .ok_or(ContractError::InvalidSignersHash)?;
@@ -49,12 +72,24 @@ pub fn validate_proof(
         ContractError::OutdatedSigners
     );
 
+    extend_signers_ttl(env, signers_epoch, signers_hash.clone());
+
     let msg_hash = message_hash_to_sign(env, signers_hash, data_hash);
 
-    ensure!(
-        validate_signatures(env, msg_hash, proof),
-        ContractError::InvalidSignatures
-    );
+    let is_valid = match proof {
+        Proof::Signatures(proof) => validate_signatures(env, msg_hash, proof),
+        Proof::Aggregate(proof) => validate_aggregate_signature(env, msg_hash, proof)?,
+        Proof::Compact(proof) => {
+            let signers = storage::try_signers_by_epoch(env, signers_epoch)
+                .ok_or(ContractError::InvalidSignersHash)?;
+            let proof =
+                SignaturesProof::from_compact(proof, signers).ok_or(ContractError::InvalidProof)?;
+
+            validate_signatures(env, msg_hash, proof)
+        }
+    };
+
+    ensure!(is_valid, ContractError::InvalidSignatures);
 
     Ok(is_latest_signers)
 }
@@ -68,6 +103,11 @@ pub fn rotate_signers(
 
     update_rotation_timestamp(env, enforce_rotation_delay)?;
 
+    // A pending proposal can only be executed while its proposers are the latest signers, so it's superseded by the rotation
+    if let Some(pending_signers) = storage::try_pending_signers(env) {
+        remove_pending_signers(env, pending_signers);
+    }
+
     let new_signers_hash = new_signers.hash(env);
 
     let new_epoch = storage::epoch(env) + 1;
@@ -76,6 +116,8 @@ pub fn rotate_signers(
 
     storage::set_signers_hash_by_epoch(env, new_epoch, &new_signers_hash);
 
+    storage::set_signers_by_epoch(env, new_epoch, &new_signers);
+
     ensure!(
         storage::try_epoch_by_signers_hash(env, new_signers_hash.clone()).is_none(),
         ContractError::DuplicateSigners
@@ -93,12 +135,209 @@ pub fn rotate_signers(
     Ok(())
 }
 
-fn message_hash_to_sign(env: &Env, signers_hash: BytesN<32>, data_hash: &BytesN<32>) -> BytesN<32> {
+pub fn propose_signers(
+    env: &Env,
+    new_signers: WeightedSigners,
+    activation_timestamp: u64,
+) -> Result<(), ContractError> {
+    validate_signers(env, &new_signers)?;
+
+    ensure!(
+        storage::try_pending_signers(env).is_none(),
+        ContractError::PendingSignersExists
+    );
+
+    // Prevent a cancelled proposal from being replayed with the same proof
+    let proposal_hash = new_signers.signers_proposal_hash(env, activation_timestamp);
+    ensure!(
+        !storage::is_cancelled_signers_proposal(env, proposal_hash),
+        ContractError::CancelledSignersProposal
+    );
+
+    // The activation timestamp has to leave at least the minimum rotation delay for the proposal to be reviewed
+    let current_timestamp = env.ledger().timestamp();
+    let earliest_activation_timestamp = current_timestamp
+        .checked_add(storage::minimum_rotation_delay(env))
+        .ok_or(ContractError::InsufficientRotationDelay)?;
+    ensure!(
+        activation_timestamp >= earliest_activation_timestamp,
+        ContractError::InsufficientRotationDelay
+    );
+
+    let new_signers_hash = new_signers.hash(env);
+    ensure!(
+        storage::try_epoch_by_signers_hash(env, new_signers_hash.clone()).is_none(),
+        ContractError::DuplicateSigners
+    );
+
+    let epoch = storage::epoch(env);
+
+    storage::set_pending_signers(
+        env,
+        &PendingSigners {
+            signers: new_signers.clone(),
+            activation_timestamp,
+            epoch,
+        },
+    );
+
+    SignersProposedEvent {
+        epoch,
+        signers_hash: new_signers_hash,
+        activation_timestamp,
+        signers: new_signers,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+pub fn execute_rotation(env: &Env) -> Result<(), ContractError> {
+    let pending_signers =
+        storage::try_pending_signers(env).ok_or(ContractError::NoPendingSigners)?;
+
+    ensure!(
+        env.ledger().timestamp() >= pending_signers.activation_timestamp,
+        ContractError::PendingSignersNotActive
+    );
+
+    // The proposal is only valid while its proposers are still the latest signers
+    ensure!(
+        pending_signers.epoch == storage::epoch(env),
+        ContractError::NotLatestSigners
+    );
+
+    storage::remove_pending_signers(env);
+
+    // The rotation delay was already enforced when the rotation was proposed
+    rotate_signers(env, pending_signers.signers, false)
+}
+
+/// Cancel the pending rotation. A rotation that has become active can only be cancelled once it's stale,
+/// i.e. its proposers are no longer the latest signers, since it can't be executed anymore.
+pub fn cancel_rotation(env: &Env) -> Result<(), ContractError> {
+    let pending_signers =
+        storage::try_pending_signers(env).ok_or(ContractError::NoPendingSigners)?;
+
+    ensure!(
+        env.ledger().timestamp() < pending_signers.activation_timestamp
+            || pending_signers.epoch != storage::epoch(env),
+        ContractError::PendingSignersActive
+    );
+
+    remove_pending_signers(env, pending_signers);
+
+    Ok(())
+}
+
+/// Remove the pending rotation and prevent the same proposal from being replayed.
+fn remove_pending_signers(env: &Env, pending_signers: PendingSigners) {
+    storage::remove_pending_signers(env);
+    storage::set_cancelled_signers_proposal_status(
+        env,
+        pending_signers
+            .signers
+            .signers_proposal_hash(env, pending_signers.activation_timestamp),
+    );
+
+    SignersProposalCancelledEvent {
+        epoch: pending_signers.epoch,
+        signers_hash: pending_signers.signers.hash(env),
+        activation_timestamp: pending_signers.activation_timestamp,
+    }
+    .emit(env);
+}
+
+/// Register a BLS12-381 public key, so that it can be part of rotated in signer sets.
+///
+/// The `proof_of_possession` is a signature over the uncompressed `public_key` bytes. Requiring it prevents
+/// rogue key attacks, where a key is chosen to cancel out the keys of other signers in an aggregated signature.
+pub fn register_bls_public_key(
+    env: &Env,
+    public_key: BytesN<96>,
+    proof_of_possession: BytesN<192>,
+) -> Result<(), ContractError> {
+    ensure!(
+        !storage::is_bls_public_key_registered(env, public_key.clone()),
+        ContractError::BlsPublicKeyAlreadyRegistered
+    );
+
+    let point = G1Affine::from_bytes(public_key.clone());
+
+    ensure!(
+        env.crypto().bls12_381().g1_is_in_subgroup(&point)
+            && bls_verify(
+                env,
+                point,
+                &public_key.clone().into(),
+                BLS_POP_DST,
+                proof_of_possession,
+            ),
+        ContractError::InvalidProofOfPossession
+    );
+
+    storage::set_bls_public_key_registered_status(env, public_key.clone());
+
+    BlsPublicKeyRegisteredEvent { public_key }.emit(env);
+
+    Ok(())
+}
+
+pub fn prune_signers(env: &Env, epochs: Vec<u64>) -> Result<(), ContractError> {
+    let current_epoch = storage::epoch(env);
+    let previous_signer_retention = storage::previous_signer_retention(env);
+
+    for epoch in epochs.into_iter() {
+        let signers_hash =
+            storage::try_signers_hash_by_epoch(env, epoch).ok_or(ContractError::InvalidEpoch)?;
+
+        ensure!(
+            current_epoch - epoch > previous_signer_retention,
+            ContractError::SignersNotExpired
+        );
+
+        storage::remove_signers_hash_by_epoch(env, epoch);
+        storage::remove_signers_by_epoch(env, epoch);
+        storage::remove_epoch_by_signers_hash(env, signers_hash.clone());
+
+        SignersPrunedEvent {
+            epoch,
+            signers_hash,
+        }
+        .emit(env);
+    }
+
+    Ok(())
+}
+
+/// Extend the TTL of the stored signers of `epoch`, so that signers in use are never archived.
+fn extend_signers_ttl(env: &Env, epoch: u64, signers_hash: BytesN<32>) {
+    storage::extend_signers_hash_by_epoch_ttl(
+        env,
+        epoch,
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
+    storage::extend_epoch_by_signers_hash_ttl(
+        env,
+        signers_hash,
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
+    storage::extend_signers_by_epoch_ttl(
+        env,
+        epoch,
+        PERSISTENT_TTL_THRESHOLD,
+        PERSISTENT_TTL_EXTEND_TO,
+    );
+}
+
+fn message_hash_to_sign(env: &Env, signers_hash: BytesN<32>, data_hash: &BytesN<32>) -> Hash<32> {
     let mut msg: Bytes = storage::domain_separator(env).into();
     msg.extend_from_array(&signers_hash.to_array());
     msg.extend_from_array(&data_hash.to_array());
 
-    env.crypto().keccak256(&msg).into()
+    env.crypto().keccak256(&msg)
 }
 
 fn update_rotation_timestamp(env: &Env, enforce_rotation_delay: bool) -> Result<(), ContractError> {
@@ -117,33 +356,163 @@ fn update_rotation_timestamp(env: &Env, enforce_rotation_delay: bool) -> Result<
     Ok(())
 }
 
-fn validate_signatures(env: &Env, msg_hash: BytesN<32>, proof: Proof) -> bool {
+fn validate_signatures(env: &Env, msg_hash: Hash<32>, proof: SignaturesProof) -> bool {
     let mut total_weight = 0u128;
 
     for ProofSigner {
-        signer: WeightedSigner {
-            signer: public_key,
-            weight,
-        },
+        signer: WeightedSigner { signer, weight },
         signature,
     } in proof.signers.iter()
     {
-        if let ProofSignature::Signed(signature) = signature {
-            env.crypto()
-                .ed25519_verify(&public_key, msg_hash.as_ref(), &signature);
+        match (signer, signature) {
+            (_, ProofSignature::Unsigned) => continue,
+            (SignerPublicKey::Ed25519(public_key), ProofSignature::Signed(signature)) => {
+                env.crypto()
+                    .ed25519_verify(&public_key, msg_hash.to_bytes().as_ref(), &signature);
+            }
+            (
+                SignerPublicKey::Secp256k1(public_key),
+                ProofSignature::SignedSecp256k1(signature),
+            ) => {
+                if secp256k1_recover_compressed(env, &msg_hash, &signature) != Some(public_key) {
+                    return false;
+                }
+            }
+            // The signature scheme doesn't match the signer's public key
+            _ => return false,
+        }
 
-            total_weight = total_weight.checked_add(weight).unwrap();
+        total_weight = total_weight.checked_add(weight).unwrap();
 
-            if total_weight >= proof.threshold {
-                return true;
-            }
+        if total_weight >= proof.threshold {
+            return true;
         }
     }
 
     false
 }
 
-/// Check if signer set is valid, i.e signer/pub key hash are in sorted order,
+/// Verify the aggregated BLS12-381 signature of the signers selected by the proof's bitmap.
+/// Returns an error if the bitmap doesn't match the signer set, or if it selects a non-BLS signer.
+/// The unused high bits of the bitmap's last byte must be unset, so that the bitmap of a selection of signers is unique.
+fn validate_aggregate_signature(
+    env: &Env,
+    msg_hash: Hash<32>,
+    proof: AggregateProof,
+) -> Result<bool, ContractError> {
+    let AggregateProof {
+        signers: WeightedSigners {
+            signers, threshold, ..
+        },
+        signer_bitmap,
+        signature,
+    } = proof;
+
+    ensure!(
+        signer_bitmap.len() == signers.len().div_ceil(8),
+        ContractError::InvalidProof
+    );
+
+    let unused_bits = signer_bitmap.len() * 8 - signers.len();
+    ensure!(
+        signer_bitmap
+            .last()
+            .is_none_or(|byte| u32::from(byte) >> (8 - unused_bits) == 0),
+        ContractError::InvalidProof
+    );
+
+    let bls = env.crypto().bls12_381();
+    let mut aggregate_public_key: Option<G1Affine> = None;
+    let mut total_weight = 0u128;
+
+    for (i, WeightedSigner { signer, weight }) in signers.iter().enumerate() {
+        let i = i as u32;
+        if signer_bitmap.get_unchecked(i / 8) & (1 << (i % 8)) == 0 {
+            continue;
+        }
+
+        let SignerPublicKey::Bls12381(public_key) = signer else {
+            return Err(ContractError::InvalidProof);
+        };
+        let public_key = G1Affine::from_bytes(public_key);
+
+        aggregate_public_key = Some(match aggregate_public_key {
+            Some(aggregate_public_key) => bls.g1_add(&aggregate_public_key, &public_key),
+            None => public_key,
+        });
+        total_weight = total_weight.checked_add(weight).unwrap();
+    }
+
+    let Some(aggregate_public_key) = aggregate_public_key else {
+        return Ok(false);
+    };
+
+    if total_weight < threshold {
+        return Ok(false);
+    }
+
+    Ok(bls_verify(
+        env,
+        aggregate_public_key,
+        &msg_hash.to_bytes().into(),
+        BLS_SIGNATURE_DST,
+        signature,
+    ))
+}
+
+/// Verify the BLS12-381 `signature` of `public_key` over `msg`, hashed to G2 with the domain separation tag `dst`.
+fn bls_verify(
+    env: &Env,
+    public_key: G1Affine,
+    msg: &Bytes,
+    dst: &[u8],
+    signature: BytesN<192>,
+) -> bool {
+    let bls = env.crypto().bls12_381();
+    let msg_point = bls.hash_to_g2(msg, &Bytes::from_slice(env, dst));
+
+    // e(pk, H(m)) == e(g1, sig)  <=>  e(pk, H(m)) * e(-g1, sig) == 1
+    bls.pairing_check(
+        vec![
+            env,
+            public_key,
+            G1Affine::from_array(env, &BLS_G1_GENERATOR_NEG),
+        ],
+        vec![env, msg_point, G2Affine::from_bytes(signature)],
+    )
+}
+
+/// Recover the SEC-1 compressed secp256k1 public key that produced the recoverable `signature` over `msg_hash`.
+/// The recovery id is accepted both in its raw (0, 1) and in its EVM-style (27, 28) encoding.
+fn secp256k1_recover_compressed(
+    env: &Env,
+    msg_hash: &Hash<32>,
+    signature: &BytesN<65>,
+) -> Option<BytesN<33>> {
+    let signature = signature.to_array();
+
+    let recovery_id = match signature[64] {
+        id @ (0 | 1) => id,
+        id @ (27 | 28) => id - 27,
+        _ => return None,
+    };
+
+    let mut rs = [0u8; 64];
+    rs.copy_from_slice(&signature[..64]);
+
+    let public_key = env
+        .crypto()
+        .secp256k1_recover(msg_hash, &BytesN::from_array(env, &rs), recovery_id.into())
+        .to_array();
+
+    let mut compressed_key = [0u8; 33];
+    compressed_key[0] = 0x02 | (public_key[64] & 1);
+    compressed_key[1..].copy_from_slice(&public_key[1..33]);
+
+    Some(BytesN::from_array(env, &compressed_key))
+}
+
+/// Check if signer set is valid, i.e signer/pub keys are well-formed and in sorted order,
 /// weights are non-zero and sum to at least threshold
 fn validate_signers(env: &Env, weighted_signers: &WeightedSigners) -> Result<(), ContractError> {
     ensure!(
@@ -151,18 +520,19 @@ fn validate_signers(env: &Env, weighted_signers: &WeightedSigners) -> Result<(),
         ContractError::EmptySigners
     );
 
-    let mut previous_signer = BytesN::<32>::from_array(env, &[0; 32]);
+    let mut previous_signer: Option<SignerPublicKey> = None;
     let mut total_weight = 0u128;
 
     for signer in weighted_signers.signers.iter() {
         ensure!(
-            previous_signer < signer.signer,
+            is_valid_public_key(env, &signer.signer)
+                && previous_signer < Some(signer.signer.clone()),
             ContractError::InvalidSigners
         );
 
         ensure!(signer.weight != 0, ContractError::InvalidWeight);
 
-        previous_signer = signer.signer;
+        previous_signer = Some(signer.signer);
         total_weight = total_weight
             .checked_add(signer.weight)
             .ok_or(ContractError::WeightOverflow)?;
@@ -176,3 +546,14 @@ fn validate_signers(env: &Env, weighted_signers: &WeightedSigners) -> Result<(),
 
     Ok(())
 }
+
+/// Check if the public key is well-formed. BLS12-381 public keys must have been registered with a proof of possession.
+fn is_valid_public_key(env: &Env, public_key: &SignerPublicKey) -> bool {
+    match public_key {
+        SignerPublicKey::Ed25519(_) => true,
+        SignerPublicKey::Secp256k1(public_key) => matches!(public_key.get(0), Some(0x02 | 0x03)),
+        SignerPublicKey::Bls12381(public_key) => {
+            storage::is_bls_public_key_registered(env, public_key.clone())
+        }
+    }
+}
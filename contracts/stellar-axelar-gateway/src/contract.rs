@@ -1,20 +1,30 @@
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Vec};
 use stellar_axelar_std::events::Event;
+use stellar_axelar_std::ttl::{PERSISTENT_TTL_EXTEND_TO, PERSISTENT_TTL_THRESHOLD};
 use stellar_axelar_std::{
     ensure, interfaces, when_not_paused, Operatable, Ownable, Pausable, Upgradable,
 };
 
 use crate::error::ContractError;
-use crate::event::{ContractCalledEvent, MessageApprovedEvent, MessageExecutedEvent};
+use crate::event::{
+    ChainAllowlistDisabledEvent, ChainAllowlistEnabledEvent, ContractCalledEvent,
+    DestinationChainRemovedEvent, DestinationChainSetEvent, MessageApprovalExpirySetEvent,
+    MessageApprovalSkippedEvent, MessageApprovedEvent, MessageExecutedEvent, MessageRevokedEvent,
+    MessagesRootApprovedEvent, ScopePausedEvent, ScopeUnpausedEvent,
+};
 use crate::interface::AxelarGatewayInterface;
 use crate::messaging_interface::AxelarGatewayMessagingInterface;
 use crate::storage::{MessageApprovalKey, MessageApprovalValue};
-use crate::types::{CommandType, Message, Proof, WeightedSigners};
+use crate::types::{
+    CommandType, Message, MessageApprovalStatus, MessageInclusionProof, MessageStatus, PauseScope,
+    PendingSigners, Proof, SignerPublicKey, WeightedSigners,
+};
 use crate::{auth, storage};
 
 #[contract]
 #[derive(Operatable, Ownable, Pausable, Upgradable)]
+#[migratable]
 pub struct AxelarGateway;
 
 #[contractimpl]
@@ -50,9 +60,36 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
         destination_chain: String,
         destination_address: String,
         payload: Bytes,
-    ) {
+    ) -> Result<String, ContractError> {
         caller.require_auth();
 
+        ensure!(
+            !storage::is_paused_scope(&env, PauseScope::Outbound),
+            ContractError::OutboundPaused
+        );
+        ensure!(
+            !storage::is_paused_scope(
+                &env,
+                PauseScope::DestinationChain(destination_chain.clone())
+            ),
+            ContractError::DestinationChainPaused
+        );
+
+        match storage::try_destination_chain_max_payload_length(&env, destination_chain.clone()) {
+            Some(max_payload_length) => ensure!(
+                payload.len() <= max_payload_length,
+                ContractError::PayloadTooLarge
+            ),
+            None => ensure!(
+                !storage::is_chain_allowlist_enabled(&env),
+                ContractError::DestinationChainNotAllowed
+            ),
+        }
+
+        let nonce = storage::try_outbound_nonce(&env, caller.clone()).unwrap_or(0);
+        storage::set_outbound_nonce(&env, caller.clone(), &(nonce + 1));
+
+        let message_id = Self::outbound_message_id(&env, &caller, nonce);
         let payload_hash = env.crypto().keccak256(&payload).into();
 
         ContractCalledEvent {
@@ -61,8 +98,15 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
             destination_address,
             payload,
             payload_hash,
+            message_id: message_id.clone(),
         }
         .emit(&env);
+
+        Ok(message_id)
+    }
+
+    fn outbound_nonce(env: Env, caller: Address) -> u64 {
+        storage::try_outbound_nonce(&env, caller).unwrap_or(0)
     }
 
     fn is_message_approved(
@@ -75,6 +119,10 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
     ) -> bool {
         let message_approval =
             Self::message_approval(&env, source_chain.clone(), message_id.clone());
+        let key = MessageApprovalKey {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+        };
 
         message_approval
             == Self::message_approval_hash(
@@ -87,6 +135,7 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
                     payload_hash,
                 },
             )
+            && !Self::is_approval_expired(&env, storage::try_message_approval_timestamp(&env, key))
     }
 
     fn is_message_executed(env: Env, source_chain: String, message_id: String) -> bool {
@@ -95,6 +144,36 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
         message_approval == MessageApprovalValue::Executed
     }
 
+    fn message_status(env: Env, source_chain: String, message_id: String) -> MessageStatus {
+        let key = MessageApprovalKey {
+            source_chain,
+            message_id,
+        };
+
+        match storage::try_message_approval(&env, key.clone()) {
+            Some(MessageApprovalValue::Approved(_))
+                if Self::is_approval_expired(
+                    &env,
+                    storage::try_message_approval_timestamp(&env, key),
+                ) =>
+            {
+                MessageStatus::NotApproved
+            }
+            Some(message_approval) => message_approval.into(),
+            None => MessageStatus::NotApproved,
+        }
+    }
+
+    fn message_statuses(env: Env, messages: Vec<(String, String)>) -> Vec<MessageStatus> {
+        let mut statuses = Vec::new(&env);
+
+        for (source_chain, message_id) in messages.into_iter() {
+            statuses.push_back(Self::message_status(env.clone(), source_chain, message_id));
+        }
+
+        statuses
+    }
+
     fn validate_message(
         env: Env,
         caller: Address,
@@ -105,6 +184,10 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
     ) -> bool {
         caller.require_auth();
 
+        if Self::is_execution_paused(&env, source_chain.clone()) {
+            return false;
+        }
+
         let key = MessageApprovalKey {
             source_chain: source_chain.clone(),
             message_id: message_id.clone(),
@@ -119,8 +202,14 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
             payload_hash,
         };
 
-        if message_approval == Self::message_approval_hash(&env, message.clone()) {
-            storage::set_message_approval(&env, key, &MessageApprovalValue::Executed);
+        if message_approval == Self::message_approval_hash(&env, message.clone())
+            && !Self::is_approval_expired(
+                &env,
+                storage::try_message_approval_timestamp(&env, key.clone()),
+            )
+        {
+            storage::set_message_approval(&env, key.clone(), &MessageApprovalValue::Executed);
+            storage::remove_message_approval_timestamp(&env, key);
 
             MessageExecutedEvent { message }.emit(&env);
 
@@ -129,6 +218,61 @@ impl AxelarGatewayMessagingInterface for AxelarGateway {
 
         false
     }
+
+    fn validate_message_with_proof(
+        env: Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: BytesN<32>,
+        inclusion_proof: MessageInclusionProof,
+    ) -> bool {
+        caller.require_auth();
+
+        if Self::is_execution_paused(&env, source_chain.clone()) {
+            return false;
+        }
+
+        let key = MessageApprovalKey {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+        };
+        let message = Message {
+            source_chain,
+            message_id,
+            source_address,
+            contract_address: caller,
+            payload_hash,
+        };
+
+        if !storage::is_approved_messages_root(&env, inclusion_proof.root.clone())
+            || Self::is_approval_expired(
+                &env,
+                storage::try_messages_root_approval_timestamp(&env, inclusion_proof.root.clone()),
+            )
+            || !inclusion_proof.includes(&env, &message)
+        {
+            return false;
+        }
+
+        // Prevent replay if message is already executed, and refuse revoked messages
+        let message_approval = storage::try_message_approval(&env, key.clone())
+            .unwrap_or(MessageApprovalValue::NotApproved);
+        if matches!(
+            message_approval,
+            MessageApprovalValue::Executed | MessageApprovalValue::Revoked
+        ) {
+            return false;
+        }
+
+        storage::set_message_approval(&env, key.clone(), &MessageApprovalValue::Executed);
+        storage::remove_message_approval_timestamp(&env, key);
+
+        MessageExecutedEvent { message }.emit(&env);
+
+        true
+    }
 }
 
 #[contractimpl]
@@ -150,7 +294,7 @@ impl AxelarGatewayInterface for AxelarGateway {
         env: &Env,
         messages: Vec<Message>,
         proof: Proof,
-    ) -> Result<(), ContractError> {
+    ) -> Result<Vec<MessageApprovalStatus>, ContractError> {
         let data_hash: BytesN<32> = env
             .crypto()
             .keccak256(&(CommandType::ApproveMessages, messages.clone()).to_xdr(env))
@@ -160,31 +304,270 @@ impl AxelarGatewayInterface for AxelarGateway {
 
         ensure!(!messages.is_empty(), ContractError::EmptyMessages);
 
+        ensure!(
+            !storage::is_paused_scope(env, PauseScope::Inbound),
+            ContractError::InboundPaused
+        );
+
+        let mut statuses = Vec::new(env);
+
+        let approval_timestamp =
+            storage::try_message_approval_expiry(env).map(|_| env.ledger().timestamp());
+
+        for message in messages.into_iter() {
+            statuses.push_back(Self::approve_message(env, message, approval_timestamp));
+        }
+
+        Ok(statuses)
+    }
+
+    fn revoke_messages(
+        env: &Env,
+        messages: Vec<Message>,
+        proof: Proof,
+    ) -> Result<(), ContractError> {
+        let data_hash: BytesN<32> = env
+            .crypto()
+            .keccak256(&(CommandType::RevokeMessages, messages.clone()).to_xdr(env))
+            .into();
+
+        auth::validate_proof(env, &data_hash, proof)?;
+
+        ensure!(!messages.is_empty(), ContractError::EmptyMessages);
+
         for message in messages.into_iter() {
             let message_approval_key = MessageApprovalKey {
                 source_chain: message.source_chain.clone(),
                 message_id: message.message_id.clone(),
             };
 
-            // Prevent replay if message is already approved/executed
+            // Messages approved through a messages root have no approval entry, so they can be revoked before approval as well
             let message_approval = storage::try_message_approval(env, message_approval_key.clone())
                 .unwrap_or(MessageApprovalValue::NotApproved);
-            if message_approval != MessageApprovalValue::NotApproved {
+            if message_approval != MessageApprovalValue::NotApproved
+                && message_approval != Self::message_approval_hash(env, message.clone())
+            {
                 continue;
             }
 
             storage::set_message_approval(
                 env,
-                message_approval_key,
-                &Self::message_approval_hash(env, message.clone()),
+                message_approval_key.clone(),
+                &MessageApprovalValue::Revoked,
+            );
+            storage::remove_message_approval_timestamp(env, message_approval_key);
+
+            MessageRevokedEvent { message }.emit(env);
+        }
+
+        Ok(())
+    }
+
+    fn is_message_revoked(env: &Env, source_chain: String, message_id: String) -> bool {
+        Self::message_approval(env, source_chain, message_id) == MessageApprovalValue::Revoked
+    }
+
+    #[when_not_paused]
+    fn approve_messages_root(
+        env: &Env,
+        root: BytesN<32>,
+        proof: Proof,
+    ) -> Result<(), ContractError> {
+        let data_hash: BytesN<32> = env
+            .crypto()
+            .keccak256(&(CommandType::ApproveMessagesRoot, root.clone()).to_xdr(env))
+            .into();
+
+        auth::validate_proof(env, &data_hash, proof)?;
+
+        ensure!(
+            !storage::is_paused_scope(env, PauseScope::Inbound),
+            ContractError::InboundPaused
+        );
+
+        // Prevent replay if the root is already approved
+        if storage::is_approved_messages_root(env, root.clone()) {
+            return Ok(());
+        }
+
+        storage::set_approved_messages_root_status(env, root.clone());
+
+        if storage::try_message_approval_expiry(env).is_some() {
+            storage::set_messages_root_approval_timestamp(
+                env,
+                root.clone(),
+                &env.ledger().timestamp(),
+            );
+        }
+
+        MessagesRootApprovedEvent { root }.emit(env);
+
+        Ok(())
+    }
+
+    #[when_not_paused]
+    fn approve_message_with_proof(
+        env: &Env,
+        message: Message,
+        inclusion_proof: MessageInclusionProof,
+    ) -> Result<MessageApprovalStatus, ContractError> {
+        ensure!(
+            !storage::is_paused_scope(env, PauseScope::Inbound),
+            ContractError::InboundPaused
+        );
+
+        ensure!(
+            Self::is_messages_root_approved(env, inclusion_proof.root.clone())
+                && inclusion_proof.includes(env, &message),
+            ContractError::InvalidMessageInclusionProof
+        );
+
+        let approval_timestamp =
+            storage::try_messages_root_approval_timestamp(env, inclusion_proof.root);
+
+        Ok(Self::approve_message(env, message, approval_timestamp))
+    }
+
+    fn is_messages_root_approved(env: &Env, root: BytesN<32>) -> bool {
+        storage::is_approved_messages_root(env, root.clone())
+            && !Self::is_approval_expired(
+                env,
+                storage::try_messages_root_approval_timestamp(env, root),
+            )
+    }
+
+    fn extend_message_ttl(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+    ) -> Result<(), ContractError> {
+        let key = MessageApprovalKey {
+            source_chain,
+            message_id,
+        };
+
+        ensure!(
+            storage::try_message_approval(env, key.clone()).is_some(),
+            ContractError::MessageApprovalNotFound
+        );
+
+        storage::extend_message_approval_ttl(
+            env,
+            key.clone(),
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+
+        // The approval timestamp is only stored if an approval expiry was set when the message was approved
+        if storage::try_message_approval_timestamp(env, key.clone()).is_some() {
+            storage::extend_message_approval_timestamp_ttl(
+                env,
+                key,
+                PERSISTENT_TTL_THRESHOLD,
+                PERSISTENT_TTL_EXTEND_TO,
             );
+        }
+
+        Ok(())
+    }
+
+    fn message_approval_expiry(env: &Env) -> u64 {
+        storage::try_message_approval_expiry(env).unwrap_or(0)
+    }
+
+    fn set_message_approval_expiry(env: &Env, expiry: u64) {
+        Self::owner(env).require_auth();
+
+        if expiry == 0 {
+            storage::remove_message_approval_expiry(env);
+        } else {
+            storage::set_message_approval_expiry(env, &expiry);
+        }
+
+        MessageApprovalExpirySetEvent { expiry }.emit(env);
+    }
 
-            MessageApprovedEvent { message }.emit(env);
+    fn pause_scope(env: &Env, scope: PauseScope) {
+        Self::owner(env).require_auth();
+
+        storage::set_paused_scope_status(env, scope.clone());
+
+        ScopePausedEvent { scope }.emit(env);
+    }
+
+    fn unpause_scope(env: &Env, scope: PauseScope) {
+        Self::owner(env).require_auth();
+
+        storage::remove_paused_scope_status(env, scope.clone());
+
+        ScopeUnpausedEvent { scope }.emit(env);
+    }
+
+    fn is_scope_paused(env: &Env, scope: PauseScope) -> bool {
+        storage::is_paused_scope(env, scope)
+    }
+
+    fn enable_chain_allowlist(env: &Env) {
+        Self::operator(env).require_auth();
+
+        storage::set_chain_allowlist_enabled_status(env);
+
+        ChainAllowlistEnabledEvent {}.emit(env);
+    }
+
+    fn disable_chain_allowlist(env: &Env) {
+        Self::operator(env).require_auth();
+
+        storage::remove_chain_allowlist_enabled_status(env);
+
+        ChainAllowlistDisabledEvent {}.emit(env);
+    }
+
+    fn is_chain_allowlist_enabled(env: &Env) -> bool {
+        storage::is_chain_allowlist_enabled(env)
+    }
+
+    fn set_destination_chain(env: &Env, destination_chain: String, max_payload_length: u32) {
+        Self::operator(env).require_auth();
+
+        storage::set_destination_chain_max_payload_length(
+            env,
+            destination_chain.clone(),
+            &max_payload_length,
+        );
+
+        DestinationChainSetEvent {
+            destination_chain,
+            max_payload_length,
         }
+        .emit(env);
+    }
+
+    fn remove_destination_chain(env: &Env, destination_chain: String) -> Result<(), ContractError> {
+        Self::operator(env).require_auth();
+
+        ensure!(
+            storage::try_destination_chain_max_payload_length(env, destination_chain.clone())
+                .is_some(),
+            ContractError::DestinationChainNotAllowed
+        );
+
+        storage::remove_destination_chain_max_payload_length(env, destination_chain.clone());
+
+        DestinationChainRemovedEvent { destination_chain }.emit(env);
 
         Ok(())
     }
 
+    fn is_destination_chain(env: &Env, destination_chain: String) -> bool {
+        storage::try_destination_chain_max_payload_length(env, destination_chain).is_some()
+    }
+
+    fn max_payload_length(env: &Env, destination_chain: String) -> Result<u32, ContractError> {
+        storage::try_destination_chain_max_payload_length(env, destination_chain)
+            .ok_or(ContractError::DestinationChainNotAllowed)
+    }
+
     fn rotate_signers(
         env: &Env,
         signers: WeightedSigners,
@@ -208,6 +591,34 @@ impl AxelarGatewayInterface for AxelarGateway {
         Ok(())
     }
 
+    fn propose_signers(
+        env: &Env,
+        signers: WeightedSigners,
+        proof: Proof,
+        activation_timestamp: u64,
+    ) -> Result<(), ContractError> {
+        let data_hash: BytesN<32> = signers.signers_proposal_hash(env, activation_timestamp);
+
+        let is_latest_signers = auth::validate_proof(env, &data_hash, proof)?;
+        ensure!(is_latest_signers, ContractError::NotLatestSigners);
+
+        auth::propose_signers(env, signers, activation_timestamp)
+    }
+
+    fn execute_rotation(env: &Env) -> Result<(), ContractError> {
+        auth::execute_rotation(env)
+    }
+
+    fn cancel_rotation(env: &Env) -> Result<(), ContractError> {
+        Self::operator(env).require_auth();
+
+        auth::cancel_rotation(env)
+    }
+
+    fn pending_signers(env: &Env) -> Result<PendingSigners, ContractError> {
+        storage::try_pending_signers(env).ok_or(ContractError::NoPendingSigners)
+    }
+
     fn epoch(env: &Env) -> u64 {
         storage::epoch(env)
     }
@@ -221,6 +632,46 @@ impl AxelarGatewayInterface for AxelarGateway {
         storage::try_signers_hash_by_epoch(env, epoch).ok_or(ContractError::InvalidEpoch)
     }
 
+    fn signers_by_epoch(env: &Env, epoch: u64) -> Result<WeightedSigners, ContractError> {
+        storage::try_signers_by_epoch(env, epoch).ok_or(ContractError::InvalidEpoch)
+    }
+
+    fn current_signers(env: &Env) -> Result<WeightedSigners, ContractError> {
+        Self::signers_by_epoch(env, storage::epoch(env))
+    }
+
+    fn prune_signers(env: &Env, epochs: Vec<u64>) -> Result<(), ContractError> {
+        auth::prune_signers(env, epochs)
+    }
+
+    fn is_signer(env: &Env, epoch: u64, signer: SignerPublicKey) -> bool {
+        let current_epoch = storage::epoch(env);
+
+        if epoch > current_epoch || current_epoch - epoch > storage::previous_signer_retention(env)
+        {
+            return false;
+        }
+
+        storage::try_signers_by_epoch(env, epoch).is_some_and(|signers| {
+            signers
+                .signers
+                .iter()
+                .any(|weighted_signer| weighted_signer.signer == signer)
+        })
+    }
+
+    fn is_bls_public_key_registered(env: &Env, public_key: BytesN<96>) -> bool {
+        storage::is_bls_public_key_registered(env, public_key)
+    }
+
+    fn register_bls_public_key(
+        env: &Env,
+        public_key: BytesN<96>,
+        proof_of_possession: BytesN<192>,
+    ) -> Result<(), ContractError> {
+        auth::register_bls_public_key(env, public_key, proof_of_possession)
+    }
+
     fn validate_proof(
         env: &Env,
         data_hash: BytesN<32>,
@@ -245,7 +696,127 @@ impl AxelarGateway {
         storage::try_message_approval(env, key).unwrap_or(MessageApprovalValue::NotApproved)
     }
 
+    /// Derive the id of an outbound message from the gateway, the `caller` and its `nonce`,
+    /// formatted as a 0x-prefixed hex string of the hash.
+    fn outbound_message_id(env: &Env, caller: &Address, nonce: u64) -> String {
+        const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+        let hash: BytesN<32> = env
+            .crypto()
+            .keccak256(&(env.current_contract_address(), caller.clone(), nonce).to_xdr(env))
+            .into();
+
+        let mut message_id = [0u8; 66];
+        message_id[..2].copy_from_slice(b"0x");
+        for (i, byte) in hash.to_array().iter().enumerate() {
+            message_id[2 + 2 * i] = HEX_CHARS[(byte >> 4) as usize];
+            message_id[3 + 2 * i] = HEX_CHARS[(byte & 0x0f) as usize];
+        }
+
+        String::from_bytes(env, &message_id)
+    }
+
+    fn is_execution_paused(env: &Env, source_chain: String) -> bool {
+        storage::is_paused_scope(env, PauseScope::Execution)
+            || storage::is_paused_scope(env, PauseScope::SourceChain(source_chain))
+    }
+
+    /// Approvals expire once the configured expiry has passed since their approval timestamp.
+    /// Approvals without a timestamp were made while no expiry was configured, and never expire.
+    fn is_approval_expired(env: &Env, approval_timestamp: Option<u64>) -> bool {
+        match (
+            storage::try_message_approval_expiry(env),
+            approval_timestamp,
+        ) {
+            (Some(expiry), Some(approval_timestamp)) => {
+                env.ledger().timestamp() > approval_timestamp.saturating_add(expiry)
+            }
+            _ => false,
+        }
+    }
+
     fn message_approval_hash(env: &Env, message: Message) -> MessageApprovalValue {
-        MessageApprovalValue::Approved(env.crypto().keccak256(&message.to_xdr(env)).into())
+        MessageApprovalValue::Approved(message.hash(env))
+    }
+
+    /// Approves `message` unless it's already approved, executed or revoked, or its source chain is paused.
+    /// Approvals without an `approval_timestamp` never expire.
+    fn approve_message(
+        env: &Env,
+        message: Message,
+        approval_timestamp: Option<u64>,
+    ) -> MessageApprovalStatus {
+        let message_approval_key = MessageApprovalKey {
+            source_chain: message.source_chain.clone(),
+            message_id: message.message_id.clone(),
+        };
+
+        // Prevent replay if message is already approved/executed. Expired approvals can be approved again.
+        let message_approval =
+            match storage::try_message_approval(env, message_approval_key.clone()) {
+                Some(MessageApprovalValue::Approved(_))
+                    if Self::is_approval_expired(
+                        env,
+                        storage::try_message_approval_timestamp(env, message_approval_key.clone()),
+                    ) =>
+                {
+                    MessageApprovalValue::NotApproved
+                }
+                message_approval => message_approval.unwrap_or(MessageApprovalValue::NotApproved),
+            };
+
+        match message_approval {
+            MessageApprovalValue::NotApproved
+                if storage::is_paused_scope(
+                    env,
+                    PauseScope::SourceChain(message.source_chain.clone()),
+                ) =>
+            {
+                Self::skip_message_approval(env, message, MessageApprovalStatus::SourceChainPaused)
+            }
+            MessageApprovalValue::NotApproved => {
+                storage::set_message_approval(
+                    env,
+                    message_approval_key.clone(),
+                    &Self::message_approval_hash(env, message.clone()),
+                );
+
+                match approval_timestamp {
+                    Some(approval_timestamp) => storage::set_message_approval_timestamp(
+                        env,
+                        message_approval_key,
+                        &approval_timestamp,
+                    ),
+                    None => storage::remove_message_approval_timestamp(env, message_approval_key),
+                }
+
+                MessageApprovedEvent { message }.emit(env);
+
+                MessageApprovalStatus::NewlyApproved
+            }
+            MessageApprovalValue::Approved(_) => {
+                Self::skip_message_approval(env, message, MessageApprovalStatus::AlreadyApproved)
+            }
+            MessageApprovalValue::Executed => {
+                Self::skip_message_approval(env, message, MessageApprovalStatus::AlreadyExecuted)
+            }
+            MessageApprovalValue::Revoked => {
+                Self::skip_message_approval(env, message, MessageApprovalStatus::AlreadyRevoked)
+            }
+        }
+    }
+
+    fn skip_message_approval(
+        env: &Env,
+        message: Message,
+        status: MessageApprovalStatus,
+    ) -> MessageApprovalStatus {
+        MessageApprovalSkippedEvent {
+            message,
+            status: status.clone(),
+        }
+        .emit(env);
+
+        status
     }
 }
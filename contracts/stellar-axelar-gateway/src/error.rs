@@ -24,4 +24,25 @@ pub enum ContractError {
     EmptyMessages = 15,
     /// Pausable
     ContractPaused = 16,
+    /// Auth
+    SignersNotExpired = 17,
+    PendingSignersExists = 18,
+    NoPendingSigners = 19,
+    PendingSignersNotActive = 20,
+    PendingSignersActive = 21,
+    CancelledSignersProposal = 22,
+    /// Messages
+    MessageApprovalNotFound = 23,
+    /// Pausable
+    InboundPaused = 24,
+    OutboundPaused = 25,
+    DestinationChainPaused = 26,
+    /// Destination chains
+    DestinationChainNotAllowed = 27,
+    PayloadTooLarge = 28,
+    /// Auth
+    BlsPublicKeyAlreadyRegistered = 29,
+    InvalidProofOfPossession = 30,
+    /// Messages
+    InvalidMessageInclusionProof = 31,
 }
@@ -3,7 +3,7 @@ use core::fmt::Debug;
 use soroban_sdk::{Address, Bytes, BytesN, String};
 use stellar_axelar_std::IntoEvent;
 
-use crate::types::{Message, WeightedSigners};
+use crate::types::{Message, MessageApprovalStatus, PauseScope, WeightedSigners};
 
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct ContractCalledEvent {
@@ -11,6 +11,7 @@ pub struct ContractCalledEvent {
     pub destination_chain: String,
     pub destination_address: String,
     pub payload_hash: BytesN<32>,
+    pub message_id: String,
     #[data]
     pub payload: Bytes,
 }
@@ -20,6 +21,27 @@ pub struct MessageApprovedEvent {
     pub message: Message,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MessageApprovalSkippedEvent {
+    pub message: Message,
+    pub status: MessageApprovalStatus,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MessageRevokedEvent {
+    pub message: Message,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MessagesRootApprovedEvent {
+    pub root: BytesN<32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct MessageApprovalExpirySetEvent {
+    pub expiry: u64,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct MessageExecutedEvent {
     pub message: Message,
@@ -32,3 +54,57 @@ pub struct SignersRotatedEvent {
     #[data]
     pub signers: WeightedSigners,
 }
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct BlsPublicKeyRegisteredEvent {
+    pub public_key: BytesN<96>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct SignersPrunedEvent {
+    pub epoch: u64,
+    pub signers_hash: BytesN<32>,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct SignersProposedEvent {
+    pub epoch: u64,
+    pub signers_hash: BytesN<32>,
+    pub activation_timestamp: u64,
+    #[data]
+    pub signers: WeightedSigners,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct SignersProposalCancelledEvent {
+    pub epoch: u64,
+    pub signers_hash: BytesN<32>,
+    pub activation_timestamp: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ScopePausedEvent {
+    pub scope: PauseScope,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ScopeUnpausedEvent {
+    pub scope: PauseScope,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct DestinationChainSetEvent {
+    pub destination_chain: String,
+    pub max_payload_length: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct DestinationChainRemovedEvent {
+    pub destination_chain: String,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ChainAllowlistEnabledEvent {}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ChainAllowlistDisabledEvent {}
@@ -1,7 +1,9 @@
-use soroban_sdk::{Address, Bytes, Env, String};
-pub use stellar_axelar_std::AxelarExecutable;
-use stellar_axelar_std::{derive_only, ensure};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+use stellar_axelar_std::events::Event;
+use stellar_axelar_std::{derive_only, ensure, IntoEvent};
+pub use stellar_axelar_std::{AxelarExecutable, AxelarExpressExecutable};
 
+use crate::types::MessageInclusionProof;
 use crate::AxelarGatewayMessagingClient;
 
 derive_only!();
@@ -21,19 +23,33 @@ pub trait AxelarExecutableInterface: CustomAxelarExecutable + DeriveOnly {
         source_address: String,
         payload: Bytes,
     ) -> Result<(), <Self as CustomAxelarExecutable>::Error>;
+
+    /// Execute a cross-chain message that was approved as part of a messages root, with the `inclusion_proof` of the message in the root.
+    /// This function must validate the inclusion proof with the trusted gateway.
+    fn execute_with_proof(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+        inclusion_proof: MessageInclusionProof,
+    ) -> Result<(), <Self as CustomAxelarExecutable>::Error>;
 }
 
 /// Encapsulates the logic for executing a cross-chain message. This trait must be implemented by a contract to be compatible with the [`AxelarExecutableInterface`].
 ///
 /// Do NOT add the implementation of [`CustomAxelarExecutable`] to the public interface of the contract, i.e. do not annotate the `impl` block with `#[contractimpl]`
 pub trait CustomAxelarExecutable {
+    /// The contract's error type. It must have a `NotApproved` variant, which the generated [`AxelarExecutableInterface::execute`]
+    /// and [`AxelarExecutableInterface::execute_with_proof`] return if the message hasn't been approved by the gateway.
     type Error: Into<soroban_sdk::Error>;
 
     /// Custom implementation of the gateway query function that's called by [`AxelarExecutableInterface::gateway`].
     fn __gateway(env: &Env) -> Address;
 
-    /// Custom implementation of the execute function that's called by [`AxelarExecutableInterface::execute`] after validation has succeeded.
-    /// It is guaranteed that the [`validate_message`] function has already been called when this function is executed.
+    /// Custom implementation of the execute function that's called by [`AxelarExecutableInterface::execute`]
+    /// and [`AxelarExecutableInterface::execute_with_proof`] after validation has succeeded.
+    /// It is guaranteed that the [`validate_message`] or [`validate_message_with_proof`] function has already been called when this function is executed.
     fn __execute(
         env: &Env,
         source_chain: String,
@@ -69,6 +85,253 @@ pub fn validate_message<T: CustomAxelarExecutable>(
     Ok(())
 }
 
+/// Validate if a message is included in a messages root approved by the gateway.
+///
+/// This is called as part of the generated implementation of [`AxelarExecutableInterface::execute_with_proof`] before running [`CustomAxelarExecutable::__execute`].
+pub fn validate_message_with_proof<T: CustomAxelarExecutable>(
+    env: &Env,
+    source_chain: &String,
+    message_id: &String,
+    source_address: &String,
+    payload: &Bytes,
+    inclusion_proof: &MessageInclusionProof,
+) -> Result<(), ValidationError> {
+    let gateway = AxelarGatewayMessagingClient::new(env, &T::__gateway(env));
+
+    // Validate that the message is included in a messages root approved by the gateway
+    ensure!(
+        gateway.validate_message_with_proof(
+            &env.current_contract_address(),
+            source_chain,
+            message_id,
+            source_address,
+            &env.crypto().keccak256(payload).into(),
+            inclusion_proof,
+        ),
+        ValidationError::NotApproved
+    );
+
+    Ok(())
+}
+
 pub enum ValidationError {
     NotApproved,
 }
+
+/// Interface for an Axelar Express Executable app. Use the [`AxelarExpressExecutable`] derive macro to implement this interface.
+///
+/// The derive macro also implements [`AxelarExecutableInterface`], so [`AxelarExecutable`] must not be derived alongside it.
+///
+/// Besides `NotApproved`, the [`CustomAxelarExecutable::Error`] type must have `AlreadyApproved` and `AlreadyExpressExecuted` variants,
+/// which the generated [`AxelarExpressExecutableInterface::express_execute`] returns for the corresponding [`ExpressValidationError`].
+///
+/// **DO NOT IMPLEMENT THIS MANUALLY!**
+pub trait AxelarExpressExecutableInterface:
+    AxelarExecutableInterface + CustomAxelarExpressExecutable
+{
+    /// Execute a cross-chain message before it has been approved by the gateway.
+    ///
+    /// The `express_executor` is recorded for the message, and reimbursed by [`CustomAxelarExpressExecutable::__reimburse_express_executor`]
+    /// instead of running [`CustomAxelarExecutable::__execute`] again once the approved message is executed.
+    fn express_execute(
+        env: &Env,
+        express_executor: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), <Self as CustomAxelarExecutable>::Error>;
+
+    /// Return the express executor of the message, if it has been express executed and not fulfilled yet.
+    fn express_executor(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: BytesN<32>,
+    ) -> Option<Address>;
+}
+
+/// Encapsulates the logic for fulfilling express executed messages. This trait must be implemented by a contract to be compatible with the [`AxelarExpressExecutableInterface`].
+///
+/// Do NOT add the implementation of [`CustomAxelarExpressExecutable`] to the public interface of the contract, i.e. do not annotate the `impl` block with `#[contractimpl]`
+pub trait CustomAxelarExpressExecutable: CustomAxelarExecutable {
+    /// Custom implementation of the reimbursement of `express_executor`, that's called by [`AxelarExecutableInterface::execute`]
+    /// and [`AxelarExecutableInterface::execute_with_proof`] instead of [`CustomAxelarExecutable::__execute`] when the approved message has already been express executed.
+    /// It is guaranteed that the [`validate_message`] or [`validate_message_with_proof`] function has already been called when this function is executed.
+    fn __reimburse_express_executor(
+        env: &Env,
+        express_executor: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload: Bytes,
+    ) -> Result<(), Self::Error>;
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ExpressExecutedEvent {
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: String,
+    pub payload_hash: BytesN<32>,
+    #[data]
+    pub express_executor: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct ExpressExecutionFulfilledEvent {
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: String,
+    pub payload_hash: BytesN<32>,
+    #[data]
+    pub express_executor: Address,
+}
+
+/// Record `express_executor` as the express executor of a message that hasn't been approved by the gateway yet.
+///
+/// This is called as part of the generated implementation of [`AxelarExpressExecutableInterface::express_execute`] before running [`CustomAxelarExecutable::__execute`].
+pub fn set_express_executor<T: CustomAxelarExpressExecutable>(
+    env: &Env,
+    express_executor: &Address,
+    source_chain: &String,
+    message_id: &String,
+    source_address: &String,
+    payload: &Bytes,
+) -> Result<(), ExpressValidationError> {
+    express_executor.require_auth();
+
+    let gateway = AxelarGatewayMessagingClient::new(env, &T::__gateway(env));
+    let payload_hash: BytesN<32> = env.crypto().keccak256(payload).into();
+
+    // Approved messages must be executed directly
+    ensure!(
+        !gateway.is_message_executed(source_chain, message_id)
+            && !gateway.is_message_approved(
+                source_chain,
+                message_id,
+                source_address,
+                &env.current_contract_address(),
+                &payload_hash,
+            ),
+        ExpressValidationError::AlreadyApproved
+    );
+
+    let key = storage::express_executor_key(
+        source_chain.clone(),
+        message_id.clone(),
+        source_address.clone(),
+        payload_hash.clone(),
+    );
+    ensure!(
+        !env.storage().persistent().has(&key),
+        ExpressValidationError::AlreadyExpressExecuted
+    );
+
+    env.storage().persistent().set(&key, express_executor);
+    stellar_axelar_std::ttl::extend_persistent_ttl(env, &key);
+
+    ExpressExecutedEvent {
+        source_chain: source_chain.clone(),
+        message_id: message_id.clone(),
+        source_address: source_address.clone(),
+        payload_hash,
+        express_executor: express_executor.clone(),
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+/// Return the express executor of a message, if it has been express executed and not fulfilled yet.
+pub fn express_executor(
+    env: &Env,
+    source_chain: String,
+    message_id: String,
+    source_address: String,
+    payload_hash: BytesN<32>,
+) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&storage::express_executor_key(
+            source_chain,
+            message_id,
+            source_address,
+            payload_hash,
+        ))
+}
+
+/// Remove and return the express executor of a message, so that it's only reimbursed once.
+///
+/// This is called as part of the generated implementation of [`AxelarExecutableInterface::execute`] and [`AxelarExecutableInterface::execute_with_proof`] after validation has succeeded.
+pub fn fulfill_express_execution(
+    env: &Env,
+    source_chain: &String,
+    message_id: &String,
+    source_address: &String,
+    payload: &Bytes,
+) -> Option<Address> {
+    let payload_hash: BytesN<32> = env.crypto().keccak256(payload).into();
+    let key = storage::express_executor_key(
+        source_chain.clone(),
+        message_id.clone(),
+        source_address.clone(),
+        payload_hash.clone(),
+    );
+
+    let express_executor: Address = env.storage().persistent().get(&key)?;
+    env.storage().persistent().remove(&key);
+
+    ExpressExecutionFulfilledEvent {
+        source_chain: source_chain.clone(),
+        message_id: message_id.clone(),
+        source_address: source_address.clone(),
+        payload_hash,
+        express_executor: express_executor.clone(),
+    }
+    .emit(env);
+
+    Some(express_executor)
+}
+
+pub enum ExpressValidationError {
+    AlreadyApproved,
+    AlreadyExpressExecuted,
+}
+
+/// Data keys of the express executable. These keys break naming conventions on purpose,
+/// to avoid collisions with the data keys of the contract deriving the interface.
+mod storage {
+    #![allow(non_camel_case_types)]
+
+    use soroban_sdk::{contracttype, BytesN, String};
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct ExpressExecutorKey {
+        pub source_chain: String,
+        pub message_id: String,
+        pub source_address: String,
+        pub payload_hash: BytesN<32>,
+    }
+
+    #[contracttype]
+    pub enum DataKey {
+        Executable_ExpressExecutor(ExpressExecutorKey),
+    }
+
+    pub const fn express_executor_key(
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: BytesN<32>,
+    ) -> DataKey {
+        DataKey::Executable_ExpressExecutor(ExpressExecutorKey {
+            source_chain,
+            message_id,
+            source_address,
+            payload_hash,
+        })
+    }
+}
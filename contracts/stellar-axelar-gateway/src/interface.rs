@@ -1,8 +1,11 @@
-use soroban_sdk::{contractclient, BytesN, Env, Vec};
+use soroban_sdk::{contractclient, BytesN, Env, String, Vec};
 use stellar_axelar_std::interfaces::{OperatableInterface, OwnableInterface, UpgradableInterface};
 
 use crate::error::ContractError;
-use crate::types::{Message, Proof, WeightedSigners};
+use crate::types::{
+    Message, MessageApprovalStatus, MessageInclusionProof, PauseScope, PendingSigners, Proof,
+    SignerPublicKey, WeightedSigners,
+};
 use crate::AxelarGatewayMessagingInterface;
 
 #[contractclient(name = "AxelarGatewayClient")]
@@ -23,24 +26,183 @@ pub trait AxelarGatewayInterface:
     /// This function allows the approval of multiple messages using a cryptographic proof.
     /// It ensures that the messages are not empty and prevents replay attacks by checking
     /// if the messages have already been approved or executed.
+    /// Messages that have already been approved or executed, or whose source chain is paused, are skipped,
+    /// and a `MessageApprovalSkippedEvent` is emitted for them.
+    /// Messages whose approval has expired are approved again, which restarts their expiry.
     ///
     /// # Arguments
     /// * `messages` - A vector of messages to be approved.
     /// * `proof` - The cryptographic proof used to validate the approval.
     ///
+    /// # Returns
+    /// - The approval status of each message, in the same order as `messages`.
+    ///
     /// # Errors
     /// - [`ContractError::EmptyMessages`]: If the provided messages vector is empty.
+    /// - [`ContractError::InboundPaused`]: If inbound approvals are paused.
     /// - Any error from `auth::validate_proof` due to an invalid proof.
     fn approve_messages(
         env: &Env,
         messages: Vec<Message>,
         proof: Proof,
+    ) -> Result<Vec<MessageApprovalStatus>, ContractError>;
+
+    /// Revokes a collection of messages that haven't been executed yet, with the provided proof.
+    ///
+    /// Revoked messages are refused by `validate_message` and `validate_message_with_proof`, and can't be approved again.
+    /// Messages that have already been executed, or were approved with a different payload, are skipped.
+    ///
+    /// # Arguments
+    /// * `messages` - A vector of messages to be revoked.
+    /// * `proof` - The cryptographic proof used to validate the revocation.
+    ///
+    /// # Errors
+    /// - [`ContractError::EmptyMessages`]: If the provided messages vector is empty.
+    /// - Any error from `auth::validate_proof` due to an invalid proof.
+    fn revoke_messages(
+        env: &Env,
+        messages: Vec<Message>,
+        proof: Proof,
+    ) -> Result<(), ContractError>;
+
+    /// Returns true if the message identified by `source_chain` and `message_id` has been revoked.
+    fn is_message_revoked(env: &Env, source_chain: String, message_id: String) -> bool;
+
+    /// Approves a Merkle `root` over a batch of messages with the provided proof.
+    ///
+    /// Instead of storing an approval per message, only the root is stored. Each message in the batch
+    /// is then executed with `validate_message_with_proof` and an inclusion proof against the root.
+    ///
+    /// # Arguments
+    /// * `root` - The Merkle root over the hashes of the approved messages.
+    /// * `proof` - The cryptographic proof used to validate the approval.
+    ///
+    /// # Errors
+    /// - [`ContractError::InboundPaused`]: If inbound approvals are paused.
+    /// - Any error from `auth::validate_proof` due to an invalid proof.
+    fn approve_messages_root(
+        env: &Env,
+        root: BytesN<32>,
+        proof: Proof,
+    ) -> Result<(), ContractError>;
+
+    /// Approves a single `message` of an approved messages root, given its `inclusion_proof`.
+    ///
+    /// The message is then approved individually, so that it's reported by `is_message_approved` and `message_status`,
+    /// and can no longer be express executed. Its approval expires with the root it was approved in.
+    /// As with `approve_messages`, messages that have already been approved, executed or revoked,
+    /// or whose source chain is paused, are skipped. Anyone can approve a message of an approved root.
+    ///
+    /// # Returns
+    /// - The approval status of `message`.
+    ///
+    /// # Errors
+    /// - [`ContractError::InboundPaused`]: If inbound approvals are paused.
+    /// - [`ContractError::InvalidMessageInclusionProof`]: If the root of `inclusion_proof` isn't approved, has expired, or doesn't include `message`.
+    fn approve_message_with_proof(
+        env: &Env,
+        message: Message,
+        inclusion_proof: MessageInclusionProof,
+    ) -> Result<MessageApprovalStatus, ContractError>;
+
+    /// Returns true if the Merkle `root` over a batch of messages has been approved.
+    fn is_messages_root_approved(env: &Env, root: BytesN<32>) -> bool;
+
+    /// Extends the TTL of the approval of the message identified by `source_chain` and `message_id`.
+    ///
+    /// Approvals are extended whenever they are approved or executed. Anyone can further extend an approval,
+    /// e.g. to keep an approved message that hasn't been executed yet, or an executed message's replay
+    /// protection, from being archived.
+    ///
+    /// # Errors
+    /// - [`ContractError::MessageApprovalNotFound`]: If the message was never approved.
+    fn extend_message_ttl(
+        env: &Env,
+        source_chain: String,
+        message_id: String,
     ) -> Result<(), ContractError>;
 
+    /// Returns the duration in seconds after which approvals that haven't been executed are refused, or 0 if approvals don't expire.
+    fn message_approval_expiry(env: &Env) -> u64;
+
+    /// Sets the duration in seconds after which approvals that haven't been executed are refused. An `expiry` of 0 disables it.
+    ///
+    /// Only approvals made while an expiry is configured can expire.
+    ///
+    /// # Authorization
+    /// - The `owner` must authorize.
+    fn set_message_approval_expiry(env: &Env, expiry: u64);
+
+    /// Pauses the given `scope` of the gateway, independently of the contract wide pause.
+    ///
+    /// - `Inbound` rejects message approvals, and `SourceChain` skips approvals of messages from that chain.
+    /// - `Outbound` rejects contract calls, and `DestinationChain` rejects contract calls to that chain.
+    /// - `Execution` refuses the validation of approved messages, and `SourceChain` refuses it for messages from that chain.
+    ///
+    /// # Authorization
+    /// - The `owner` must authorize.
+    fn pause_scope(env: &Env, scope: PauseScope);
+
+    /// Unpauses the given `scope` of the gateway.
+    ///
+    /// # Authorization
+    /// - The `owner` must authorize.
+    fn unpause_scope(env: &Env, scope: PauseScope);
+
+    /// Returns whether the given `scope` of the gateway is paused.
+    fn is_scope_paused(env: &Env, scope: PauseScope) -> bool;
+
+    /// Enables the destination chain allowlist, rejecting contract calls to chains that haven't been set via `set_destination_chain`.
+    ///
+    /// The allowlist is disabled by default, so that chains can be set up before enforcing it.
+    ///
+    /// # Authorization
+    /// - The `operator` must authorize.
+    fn enable_chain_allowlist(env: &Env);
+
+    /// Disables the destination chain allowlist, allowing contract calls to any chain.
+    ///
+    /// The maximum payload lengths of the chains that have been set are still enforced.
+    ///
+    /// # Authorization
+    /// - The `operator` must authorize.
+    fn disable_chain_allowlist(env: &Env);
+
+    /// Returns whether the destination chain allowlist is enabled.
+    fn is_chain_allowlist_enabled(env: &Env) -> bool;
+
+    /// Allows contract calls to `destination_chain` with payloads of up to `max_payload_length` bytes.
+    ///
+    /// Setting an already allowed chain updates its maximum payload length.
+    ///
+    /// # Authorization
+    /// - The `operator` must authorize.
+    fn set_destination_chain(env: &Env, destination_chain: String, max_payload_length: u32);
+
+    /// Disallows contract calls to `destination_chain`.
+    ///
+    /// # Errors
+    /// - [`ContractError::DestinationChainNotAllowed`]: If the chain is not allowed.
+    ///
+    /// # Authorization
+    /// - The `operator` must authorize.
+    fn remove_destination_chain(env: &Env, destination_chain: String) -> Result<(), ContractError>;
+
+    /// Returns whether contract calls to `destination_chain` are allowed.
+    fn is_destination_chain(env: &Env, destination_chain: String) -> bool;
+
+    /// Returns the maximum payload length of contract calls to `destination_chain`.
+    ///
+    /// # Errors
+    /// - [`ContractError::DestinationChainNotAllowed`]: If the chain is not allowed.
+    fn max_payload_length(env: &Env, destination_chain: String) -> Result<u32, ContractError>;
+
     /// Rotates to `signers` if the `proof` is valid.
     ///
     /// If `bypass_rotation_delay` is set to true, the `operator` must authorize the rotation.
     ///
+    /// Any pending rotation proposed via `propose_signers` is cancelled.
+    ///
     /// # Arguments
     /// * `signers` - The new set of weighted signers to be rotated in.
     /// * `proof` - The cryptographic proof used to validate the rotation.
@@ -48,6 +210,7 @@ pub trait AxelarGatewayInterface:
     ///
     /// # Errors
     /// - [`ContractError::NotLatestSigners`]: If the provided signers are not the latest and `bypass_rotation_delay` is false.
+    /// - [`ContractError::InvalidSigners`]: If the signers are malformed, or a BLS12-381 signer's public key isn't registered.
     /// - Any error from `auth::validate_proof` due to invalid proof.
     ///
     /// # Authorization
@@ -59,6 +222,56 @@ pub trait AxelarGatewayInterface:
         bypass_rotation_delay: bool,
     ) -> Result<(), ContractError>;
 
+    /// Proposes a rotation to `signers` that can only be executed once `activation_timestamp` is reached, if the `proof` is valid.
+    ///
+    /// Only one rotation can be pending at a time, and it must be proposed by the latest signers.
+    /// The `activation_timestamp` must be at least `minimum_rotation_delay` in the future, giving watchers a window to react.
+    ///
+    /// # Arguments
+    /// * `signers` - The new set of weighted signers to be rotated in.
+    /// * `proof` - The cryptographic proof over the signers and the activation timestamp.
+    /// * `activation_timestamp` - The ledger timestamp from which the rotation can be executed.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotLatestSigners`]: If the proof was not created by the latest signers.
+    /// - [`ContractError::PendingSignersExists`]: If another rotation is already pending.
+    /// - [`ContractError::CancelledSignersProposal`]: If the same proposal was cancelled before.
+    /// - [`ContractError::InsufficientRotationDelay`]: If `activation_timestamp` is too early.
+    /// - [`ContractError::DuplicateSigners`]: If `signers` were already used in a previous epoch.
+    /// - Any error from `auth::validate_proof` due to invalid proof.
+    fn propose_signers(
+        env: &Env,
+        signers: WeightedSigners,
+        proof: Proof,
+        activation_timestamp: u64,
+    ) -> Result<(), ContractError>;
+
+    /// Rotates to the pending signers once their activation timestamp is reached. Anyone can execute a pending rotation.
+    ///
+    /// # Errors
+    /// - [`ContractError::NoPendingSigners`]: If no rotation is pending.
+    /// - [`ContractError::PendingSignersNotActive`]: If the activation timestamp has not been reached yet.
+    /// - [`ContractError::NotLatestSigners`]: If the signers have been rotated since the rotation was proposed.
+    fn execute_rotation(env: &Env) -> Result<(), ContractError>;
+
+    /// Cancels the pending rotation before its activation timestamp is reached.
+    ///
+    /// A stale rotation, i.e. one whose proposers are no longer the latest signers, can be cancelled at any time.
+    ///
+    /// # Errors
+    /// - [`ContractError::NoPendingSigners`]: If no rotation is pending.
+    /// - [`ContractError::PendingSignersActive`]: If the activation timestamp has already been reached and the rotation is not stale.
+    ///
+    /// # Authorization
+    /// - The `operator` must authorize.
+    fn cancel_rotation(env: &Env) -> Result<(), ContractError>;
+
+    /// Returns the pending signer rotation.
+    ///
+    /// # Errors
+    /// - [`ContractError::NoPendingSigners`]: If no rotation is pending.
+    fn pending_signers(env: &Env) -> Result<PendingSigners, ContractError>;
+
     /// Returns the epoch of the gateway.
     fn epoch(env: &Env) -> u64;
 
@@ -68,6 +281,51 @@ pub trait AxelarGatewayInterface:
     /// Returns the signers hash by epoch.
     fn signers_hash_by_epoch(env: &Env, epoch: u64) -> Result<BytesN<32>, ContractError>;
 
+    /// Returns the weighted signers by epoch.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidEpoch`]: If no signers are stored for the given `epoch`.
+    fn signers_by_epoch(env: &Env, epoch: u64) -> Result<WeightedSigners, ContractError>;
+
+    /// Returns the weighted signers of the current epoch.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidEpoch`]: If no signers are stored for the current epoch.
+    fn current_signers(env: &Env) -> Result<WeightedSigners, ContractError>;
+
+    /// Removes the stored signers of the given expired `epochs`.
+    ///
+    /// Signers expire once their epoch falls outside of the previous signers retention window,
+    /// after which proofs created by them are rejected. Anyone can prune expired signers.
+    /// Pruned signers are forgotten entirely, so their hash no longer resolves to an epoch.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidEpoch`]: If no signers are stored for one of the `epochs`.
+    /// - [`ContractError::SignersNotExpired`]: If one of the `epochs` is still within the retention window.
+    fn prune_signers(env: &Env, epochs: Vec<u64>) -> Result<(), ContractError>;
+
+    /// Returns true if `signer` is part of the signers of `epoch`, and proofs of that epoch are still accepted,
+    /// i.e. `epoch` lies within the previous signers retention window.
+    fn is_signer(env: &Env, epoch: u64, signer: SignerPublicKey) -> bool;
+
+    /// Returns true if the BLS12-381 `public_key` was registered with a valid proof of possession.
+    fn is_bls_public_key_registered(env: &Env, public_key: BytesN<96>) -> bool;
+
+    /// Registers a BLS12-381 `public_key`, which is required before it can be part of a signer set that is rotated in.
+    ///
+    /// The `proof_of_possession` is a signature over the uncompressed `public_key` bytes, using the
+    /// `BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` domain separation tag. Requiring it prevents rogue key
+    /// attacks against aggregate proofs. Anyone can register a public key.
+    ///
+    /// # Errors
+    /// - [`ContractError::BlsPublicKeyAlreadyRegistered`]: If the `public_key` is already registered.
+    /// - [`ContractError::InvalidProofOfPossession`]: If the `public_key` isn't a valid G1 point or the `proof_of_possession` is invalid.
+    fn register_bls_public_key(
+        env: &Env,
+        public_key: BytesN<96>,
+        proof_of_possession: BytesN<192>,
+    ) -> Result<(), ContractError>;
+
     /// Validate the `proof` for `data_hash` created by the signers.
     /// Returns a boolean indicating if the proof was created by the latest signers.
     fn validate_proof(
@@ -30,6 +30,7 @@ cfg_if::cfg_if! {
         pub mod event;
         mod storage;
         mod contract;
+        mod migrate;
 
         pub use contract::{AxelarGateway, AxelarGatewayClient};
     }
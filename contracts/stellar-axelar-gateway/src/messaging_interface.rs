@@ -1,4 +1,7 @@
-use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env, String, Vec};
+
+use crate::error::ContractError;
+use crate::types::{MessageInclusionProof, MessageStatus};
 
 #[contractclient(name = "AxelarGatewayMessagingClient")]
 pub trait AxelarGatewayMessagingInterface {
@@ -7,13 +10,26 @@ pub trait AxelarGatewayMessagingInterface {
     /// This function is the entry point for general message passing between chains.
     ///
     /// A registered chain name on Axelar must be used for `destination_chain`.
+    ///
+    /// Returns the message id, derived deterministically from the `caller` and its outbound nonce,
+    /// which is incremented with every contract call. The message id is emitted in [`crate::event::ContractCalledEvent`],
+    /// so that relayers and gas payments can be correlated with the message.
+    ///
+    /// # Errors
+    /// - [`ContractError::DestinationChainNotAllowed`]: If the chain allowlist is enabled and `destination_chain` is not allowed by the operator.
+    /// - [`ContractError::PayloadTooLarge`]: If `payload` exceeds the maximum payload length of `destination_chain`.
+    /// - [`ContractError::OutboundPaused`]: If outbound contract calls are paused.
+    /// - [`ContractError::DestinationChainPaused`]: If contract calls to `destination_chain` are paused.
     fn call_contract(
         env: Env,
         caller: Address,
         destination_chain: String,
         destination_address: String,
         payload: Bytes,
-    );
+    ) -> Result<String, ContractError>;
+
+    /// Returns the outbound nonce of `caller`, i.e. the number of contract calls it has made.
+    fn outbound_nonce(env: Env, caller: Address) -> u64;
 
     /// Checks if a message is approved
     ///
@@ -34,9 +50,19 @@ pub trait AxelarGatewayMessagingInterface {
     /// Returns true if the message is executed, false otherwise.
     fn is_message_executed(env: Env, source_chain: String, message_id: String) -> bool;
 
+    /// Returns the status of the message identified by `source_chain` and `message_id`.
+    ///
+    /// An approved message whose approval has expired is reported as `NotApproved`.
+    fn message_status(env: Env, source_chain: String, message_id: String) -> MessageStatus;
+
+    /// Returns the status of each message identified by a `(source_chain, message_id)` pair, in the same order.
+    fn message_statuses(env: Env, messages: Vec<(String, String)>) -> Vec<MessageStatus>;
+
     /// Validates if a message is approved. If message was in approved status, status is updated to executed to avoid
     /// replay.
     ///
+    /// Returns false while execution, or messages from `source_chain`, are paused.
+    ///
     /// `caller` must be the intended `destination_address` of the contract call for validation to succeed.
     fn validate_message(
         env: Env,
@@ -46,4 +72,20 @@ pub trait AxelarGatewayMessagingInterface {
         source_address: String,
         payload_hash: BytesN<32>,
     ) -> bool;
+
+    /// Validates that a message is included in an approved messages root. If the message wasn't executed yet,
+    /// its status is updated to executed to avoid replay.
+    ///
+    /// Returns false while execution, or messages from `source_chain`, are paused.
+    ///
+    /// `caller` must be the intended `destination_address` of the contract call for validation to succeed.
+    fn validate_message_with_proof(
+        env: Env,
+        caller: Address,
+        source_chain: String,
+        message_id: String,
+        source_address: String,
+        payload_hash: BytesN<32>,
+        inclusion_proof: MessageInclusionProof,
+    ) -> bool;
 }
@@ -0,0 +1,38 @@
+use soroban_sdk::{Env, Vec};
+use stellar_axelar_std::ensure;
+use stellar_axelar_std::interfaces::CustomMigratableInterface;
+
+use crate::error::ContractError;
+use crate::types::WeightedSigners;
+use crate::{storage, AxelarGateway};
+
+impl CustomMigratableInterface for AxelarGateway {
+    /// The signer sets of all epochs within the previous signer retention window,
+    /// which were rotated in before full signer sets were stored.
+    type MigrationData = Vec<WeightedSigners>;
+    type Error = ContractError;
+
+    fn __migrate(env: &Env, migration_data: Self::MigrationData) -> Result<(), Self::Error> {
+        for signers in migration_data {
+            let epoch = storage::try_epoch_by_signers_hash(env, signers.hash(env))
+                .ok_or(ContractError::InvalidSignersHash)?;
+
+            storage::set_signers_by_epoch(env, epoch, &signers);
+        }
+
+        let current_epoch = storage::epoch(env);
+        let oldest_retained_epoch = current_epoch
+            .saturating_sub(storage::previous_signer_retention(env))
+            .max(1);
+
+        // The TTL of the signers of retained epochs is extended directly, so they all have to be stored
+        for epoch in oldest_retained_epoch..=current_epoch {
+            ensure!(
+                storage::try_signers_by_epoch(env, epoch).is_some(),
+                ContractError::InvalidEpoch
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
-use soroban_sdk::{contracttype, BytesN, String};
+use soroban_sdk::{contracttype, Address, BytesN, String};
 use stellar_axelar_std::contractstorage;
 
+use crate::types::{MessageStatus, PauseScope, PendingSigners, WeightedSigners};
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct MessageApprovalKey {
@@ -14,6 +16,18 @@ pub enum MessageApprovalValue {
     NotApproved,
     Approved(BytesN<32>),
     Executed,
+    Revoked,
+}
+
+impl From<MessageApprovalValue> for MessageStatus {
+    fn from(value: MessageApprovalValue) -> Self {
+        match value {
+            MessageApprovalValue::NotApproved => Self::NotApproved,
+            MessageApprovalValue::Approved(message_hash) => Self::Approved(message_hash),
+            MessageApprovalValue::Executed => Self::Executed,
+            MessageApprovalValue::Revoked => Self::Revoked,
+        }
+    }
 }
 
 #[contractstorage]
@@ -51,4 +65,54 @@ enum DataKey {
     #[persistent]
     #[value(u64)]
     EpochBySignersHash { signers_hash: BytesN<32> },
+
+    #[persistent]
+    #[value(WeightedSigners)]
+    SignersByEpoch { epoch: u64 },
+
+    #[persistent]
+    #[value(PendingSigners)]
+    PendingSigners,
+
+    #[persistent]
+    #[status]
+    BlsPublicKeyRegistered { public_key: BytesN<96> },
+
+    #[persistent]
+    #[status]
+    CancelledSignersProposal { proposal_hash: BytesN<32> },
+
+    #[persistent]
+    #[status]
+    ApprovedMessagesRoot { root: BytesN<32> },
+
+    #[instance]
+    #[value(u64)]
+    MessageApprovalExpiry,
+
+    #[persistent]
+    #[value(u64)]
+    MessageApprovalTimestamp {
+        message_approval_key: MessageApprovalKey,
+    },
+
+    #[persistent]
+    #[value(u64)]
+    MessagesRootApprovalTimestamp { root: BytesN<32> },
+
+    #[persistent]
+    #[status]
+    PausedScope { scope: PauseScope },
+
+    #[persistent]
+    #[value(u64)]
+    OutboundNonce { caller: Address },
+
+    #[instance]
+    #[status]
+    ChainAllowlistEnabled,
+
+    #[persistent]
+    #[value(u32)]
+    DestinationChainMaxPayloadLength { destination_chain: String },
 }
@@ -1,11 +1,18 @@
+use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
 use soroban_sdk::testutils::{Address as _, BytesN as _};
-use soroban_sdk::{vec, Address, BytesN, Env, Vec};
+use soroban_sdk::{bytesn, vec, Address, Bytes, BytesN, Env, Map, Vec};
+use stellar_axelar_std::traits::IntoVec;
 use stellar_axelar_std::{assert_auth, assert_contract_err};
 
 use super::utils::setup_env;
 use crate::error::ContractError;
-use crate::testutils::{generate_proof, generate_signers_set, randint};
-use crate::types::{ProofSignature, ProofSigner, WeightedSigner, WeightedSigners};
+use crate::testutils::{
+    bls_g1_generator, generate_aggregate_proof, generate_bls_signers_set, generate_compact_proof,
+    generate_mixed_signers_set, generate_proof, generate_secp256k1_signers_set,
+    generate_signatures_proof, generate_signers_set, randint, register_bls_signers, TestSignerSet,
+    TestSigningKey,
+};
+use crate::types::{ProofSignature, ProofSigner, SignerPublicKey, WeightedSigner, WeightedSigners};
 use crate::{AxelarGateway, AxelarGatewayClient};
 
 #[test]
@@ -96,7 +103,7 @@ fn validate_proof_fails_with_empty_signatures() {
     let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
 
     let msg_hash: BytesN<32> = BytesN::random(&env);
-    let mut proof = generate_proof(&env, msg_hash.clone(), signers);
+    let mut proof = generate_signatures_proof(&env, msg_hash.clone(), signers);
 
     let mut new_signers = Vec::new(&env);
     for signer in proof.signers.iter() {
@@ -108,7 +115,7 @@ fn validate_proof_fails_with_empty_signatures() {
     proof.signers = new_signers;
 
     assert_contract_err!(
-        client.try_validate_proof(&msg_hash, &proof),
+        client.try_validate_proof(&msg_hash, &proof.into()),
         ContractError::InvalidSignatures
     );
 }
@@ -120,7 +127,7 @@ fn validate_proof_fails_if_threshold_not_met() {
     let mut total_weight = 0u128;
 
     let msg_hash = BytesN::random(&env);
-    let mut proof = generate_proof(&env, msg_hash.clone(), signers);
+    let mut proof = generate_signatures_proof(&env, msg_hash.clone(), signers);
 
     let mut new_signers = Vec::new(&env);
     for ProofSigner { signer, signature } in proof.signers {
@@ -137,7 +144,280 @@ fn validate_proof_fails_if_threshold_not_met() {
     proof.signers = new_signers;
 
     assert_contract_err!(
-        client.try_validate_proof(&msg_hash, &proof),
+        client.try_validate_proof(&msg_hash, &proof.into()),
+        ContractError::InvalidSignatures
+    );
+}
+
+fn rotate_to(
+    env: &Env,
+    client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    new_signers: &TestSignerSet,
+) {
+    register_bls_signers(env, client, new_signers);
+
+    let data_hash = new_signers.signers.signers_rotation_hash(env);
+    let proof = generate_proof(env, data_hash, signers);
+
+    client.rotate_signers(&new_signers.signers, &proof, &false);
+}
+
+#[test]
+fn validate_proof_succeeds_with_secp256k1_signers() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers =
+        generate_secp256k1_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+    rotate_to(&env, &client, signers, &new_signers);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_proof(&env, msg_hash.clone(), new_signers);
+
+    assert!(client.validate_proof(&msg_hash, &proof));
+}
+
+#[test]
+fn validate_proof_succeeds_with_mixed_signers() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers =
+        generate_mixed_signers_set(&env, randint(2, 10), signers.domain_separator.clone());
+    rotate_to(&env, &client, signers, &new_signers);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_proof(&env, msg_hash.clone(), new_signers);
+
+    assert!(client.validate_proof(&msg_hash, &proof));
+}
+
+#[test]
+fn validate_proof_fails_with_invalid_secp256k1_signatures() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers =
+        generate_secp256k1_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+    rotate_to(&env, &client, signers, &new_signers);
+
+    let proof_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_proof(&env, proof_hash, new_signers);
+    let random_hash: BytesN<32> = BytesN::random(&env);
+
+    assert_contract_err!(
+        client.try_validate_proof(&random_hash, &proof),
+        ContractError::InvalidSignatures
+    );
+}
+
+#[test]
+fn validate_proof_fails_with_mismatched_signature_scheme() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let mut proof = generate_signatures_proof(&env, msg_hash.clone(), signers);
+
+    let mut new_signers = Vec::new(&env);
+    for ProofSigner { signer, signature } in proof.signers.iter() {
+        let signature = match signature {
+            ProofSignature::Signed(_) => {
+                ProofSignature::SignedSecp256k1(BytesN::from_array(&env, &[1; 65]))
+            }
+            signature => signature,
+        };
+        new_signers.push_back(ProofSigner { signer, signature });
+    }
+    proof.signers = new_signers;
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof.into()),
+        ContractError::InvalidSignatures
+    );
+}
+
+#[test]
+fn validate_proof_succeeds_with_aggregate_proof() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers =
+        generate_bls_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+    rotate_to(&env, &client, signers, &new_signers);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_aggregate_proof(&env, msg_hash.clone(), new_signers);
+
+    assert!(client.validate_proof(&msg_hash, &proof.into()));
+}
+
+#[test]
+fn validate_proof_fails_with_invalid_aggregate_signature() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers =
+        generate_bls_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+    rotate_to(&env, &client, signers, &new_signers);
+
+    let proof_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_aggregate_proof(&env, proof_hash, new_signers);
+    let random_hash: BytesN<32> = BytesN::random(&env);
+
+    assert_contract_err!(
+        client.try_validate_proof(&random_hash, &proof.into()),
+        ContractError::InvalidSignatures
+    );
+}
+
+#[test]
+fn validate_proof_fails_if_aggregate_threshold_not_met() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let mut new_signers = generate_bls_signers_set(&env, 3, signers.domain_separator.clone());
+    new_signers.signers.threshold = new_signers
+        .signers
+        .signers
+        .iter()
+        .map(|WeightedSigner { weight, .. }| weight)
+        .sum();
+    rotate_to(&env, &client, signers, &new_signers);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let mut proof = generate_aggregate_proof(&env, msg_hash.clone(), new_signers);
+
+    // Drop the last signer from the bitmap, the aggregated signature remains unchanged
+    proof.signer_bitmap.set(0, 0b011);
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof.into()),
+        ContractError::InvalidSignatures
+    );
+}
+
+#[test]
+fn validate_proof_fails_with_invalid_signer_bitmap_length() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers =
+        generate_bls_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+    rotate_to(&env, &client, signers, &new_signers);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let mut proof = generate_aggregate_proof(&env, msg_hash.clone(), new_signers);
+    proof.signer_bitmap.push_back(0);
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof.into()),
+        ContractError::InvalidProof
+    );
+}
+
+#[test]
+fn validate_proof_fails_with_unused_signer_bitmap_bits_set() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers = generate_bls_signers_set(&env, 3, signers.domain_separator.clone());
+    rotate_to(&env, &client, signers, &new_signers);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let mut proof = generate_aggregate_proof(&env, msg_hash.clone(), new_signers);
+    assert!(client.validate_proof(&msg_hash, &proof.clone().into()));
+
+    // Selects the same signers, with a bit beyond the last signer set
+    proof
+        .signer_bitmap
+        .set(0, proof.signer_bitmap.get_unchecked(0) | 0b1000_0000);
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof.into()),
+        ContractError::InvalidProof
+    );
+}
+
+#[test]
+fn validate_proof_fails_with_aggregate_proof_from_non_bls_signers() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let mut proof = generate_aggregate_proof(
+        &env,
+        msg_hash.clone(),
+        generate_bls_signers_set(&env, 1, signers.domain_separator.clone()),
+    );
+    let mut signer_bitmap = std::vec![0u8; signers.signers.signers.len().div_ceil(8) as usize];
+    for i in 0..signers.signers.signers.len() as usize {
+        signer_bitmap[i / 8] |= 1 << (i % 8);
+    }
+    proof.signer_bitmap = Bytes::from_slice(&env, &signer_bitmap);
+    proof.signers = signers.signers;
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof.into()),
+        ContractError::InvalidProof
+    );
+}
+
+#[test]
+fn validate_proof_succeeds_with_compact_proof() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_compact_proof(&env, msg_hash.clone(), signers);
+
+    assert!(client.validate_proof(&msg_hash, &proof.into()));
+}
+
+#[test]
+fn validate_proof_succeeds_with_compact_proof_from_previous_signers() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+
+    let new_signers = generate_mixed_signers_set(&env, randint(1, 10), BytesN::random(&env));
+    rotate_to(&env, &client, signers.clone(), &new_signers);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_compact_proof(&env, msg_hash.clone(), signers);
+
+    assert!(!client.validate_proof(&msg_hash, &proof.into()));
+}
+
+#[test]
+fn validate_proof_fails_with_compact_proof_for_unknown_signers() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let unknown_signers = generate_signers_set(&env, randint(1, 10), signers.domain_separator);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_compact_proof(&env, msg_hash.clone(), unknown_signers);
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof.into()),
+        ContractError::InvalidSignersHash
+    );
+}
+
+#[test]
+fn validate_proof_fails_with_compact_proof_index_out_of_range() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+    let num_signers = signers.signers.signers.len();
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let mut proof = generate_compact_proof(&env, msg_hash.clone(), signers);
+    let signature = proof.signatures.values().first_unchecked();
+    proof.signatures.set(num_signers, signature);
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof.into()),
+        ContractError::InvalidProof
+    );
+}
+
+#[test]
+fn validate_proof_fails_if_compact_threshold_not_met() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let mut proof = generate_compact_proof(&env, msg_hash.clone(), signers);
+    proof.signatures = Map::new(&env);
+
+    assert_contract_err!(
+        client.try_validate_proof(&msg_hash, &proof.into()),
         ContractError::InvalidSignatures
     );
 }
@@ -319,6 +599,29 @@ fn rotate_signers_fails_with_wrong_signer_order() {
     )
 }
 
+#[test]
+fn rotate_signers_fails_with_invalid_secp256k1_public_key() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let mut new_signers =
+        generate_secp256k1_signers_set(&env, randint(1, 10), BytesN::random(&env));
+
+    let WeightedSigner { weight, .. } = new_signers.signers.signers.pop_back_unchecked();
+    new_signers.signers.signers.push_back(WeightedSigner {
+        signer: SignerPublicKey::Secp256k1(BytesN::from_array(&env, &[4; 33])),
+        weight,
+    });
+
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_rotate_signers(&new_signers.signers, &proof, &true),
+        ContractError::InvalidSigners
+    );
+}
+
 #[test]
 fn rotate_signers_fails_with_duplicated_signers() {
     let (env, signers, client) = setup_env(1, randint(1, 10));
@@ -447,3 +750,127 @@ fn multi_rotate_signers() {
     let proof = generate_proof(&env, msg_hash.clone(), original_signers);
     client.validate_proof(&msg_hash, &proof);
 }
+
+#[test]
+fn register_bls_public_key_fails_with_invalid_proof_of_possession() {
+    let (env, _, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let signing_key = TestSigningKey::Bls12381([1; 32]);
+    let SignerPublicKey::Bls12381(public_key) = signing_key.public_key(&env) else {
+        panic!("not a BLS12-381 signer");
+    };
+    let other_proof_of_possession = TestSigningKey::Bls12381([2; 32]).bls_proof_of_possession(&env);
+
+    assert_contract_err!(
+        client.try_register_bls_public_key(&public_key, &other_proof_of_possession),
+        ContractError::InvalidProofOfPossession
+    );
+}
+
+#[test]
+fn register_bls_public_key_fails_if_already_registered() {
+    let (env, _, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let signing_key = TestSigningKey::Bls12381([1; 32]);
+    let SignerPublicKey::Bls12381(public_key) = signing_key.public_key(&env) else {
+        panic!("not a BLS12-381 signer");
+    };
+    let proof_of_possession = signing_key.bls_proof_of_possession(&env);
+
+    client.register_bls_public_key(&public_key, &proof_of_possession);
+
+    assert_contract_err!(
+        client.try_register_bls_public_key(&public_key, &proof_of_possession),
+        ContractError::BlsPublicKeyAlreadyRegistered
+    );
+}
+
+#[test]
+fn rotate_signers_fails_with_unregistered_bls_signer() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+
+    let new_signers =
+        generate_bls_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_rotate_signers(&new_signers.signers, &proof, &false),
+        ContractError::InvalidSigners
+    );
+}
+
+#[test]
+fn rotate_signers_fails_with_cancelling_bls_key() {
+    let (env, signers, client) = setup_env(randint(0, 10), randint(1, 10));
+    let bls = env.crypto().bls12_381();
+
+    let honest_signer = TestSigningKey::Bls12381([1; 32]);
+    let SignerPublicKey::Bls12381(honest_public_key) = honest_signer.public_key(&env) else {
+        panic!("not a BLS12-381 signer");
+    };
+    client.register_bls_public_key(
+        &honest_public_key,
+        &honest_signer.bls_proof_of_possession(&env),
+    );
+
+    // The rogue key is chosen as `sk * g1 - honest_public_key`, so that the aggregated public key of both signers
+    // is `sk * g1`, allowing the attacker to create aggregate signatures on behalf of the honest signer.
+    let attacker_secret_key = Fr::from_bytes(BytesN::from_array(&env, &[3; 32]));
+    let minus_one = Fr::from_bytes(bytesn!(
+        &env,
+        0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000
+    ));
+    let rogue_public_key = bls.g1_add(
+        &bls.g1_mul(&bls_g1_generator(&env), &attacker_secret_key),
+        &bls.g1_mul(&G1Affine::from_bytes(honest_public_key.clone()), &minus_one),
+    );
+    assert_eq!(
+        bls.g1_add(
+            &G1Affine::from_bytes(honest_public_key.clone()),
+            &rogue_public_key
+        ),
+        bls.g1_mul(&bls_g1_generator(&env), &attacker_secret_key)
+    );
+
+    // Without the secret key of the rogue key, the attacker can't prove possession of it
+    let rogue_proof_of_possession = bls.g2_mul(
+        &bls.hash_to_g2(
+            &rogue_public_key.to_bytes().into(),
+            &Bytes::from_slice(&env, b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_"),
+        ),
+        &attacker_secret_key,
+    );
+    assert_contract_err!(
+        client.try_register_bls_public_key(
+            &rogue_public_key.to_bytes(),
+            &rogue_proof_of_possession.to_bytes()
+        ),
+        ContractError::InvalidProofOfPossession
+    );
+
+    let mut weighted_signers = std::vec![
+        WeightedSigner {
+            signer: SignerPublicKey::Bls12381(honest_public_key),
+            weight: 1,
+        },
+        WeightedSigner {
+            signer: SignerPublicKey::Bls12381(rogue_public_key.to_bytes()),
+            weight: 1,
+        },
+    ];
+    weighted_signers.sort_by(|a, b| a.signer.cmp(&b.signer));
+
+    let new_signers = WeightedSigners {
+        signers: weighted_signers.into_vec(&env),
+        threshold: 2,
+        nonce: BytesN::random(&env),
+    };
+    let data_hash = new_signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_rotate_signers(&new_signers, &proof, &false),
+        ContractError::InvalidSigners
+    );
+}
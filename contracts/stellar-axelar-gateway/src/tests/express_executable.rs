@@ -0,0 +1,442 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{bytes, vec, Address, Bytes, String};
+use stellar_axelar_std::{assert_auth, assert_contract_err, events};
+
+use super::utils::setup_env;
+use crate::executable::{ExpressExecutedEvent, ExpressExecutionFulfilledEvent};
+use crate::testutils::{
+    approve_gateway_messages, generate_messages_merkle_tree, generate_proof,
+    get_approve_messages_root_hash, TestSignerSet,
+};
+use crate::types::{Message, MessageApprovalStatus, MessageInclusionProof};
+use crate::AxelarGatewayClient;
+
+mod test {
+    use core::fmt::Debug;
+
+    use soroban_sdk::{
+        contract, contracterror, contractimpl, contracttype, Address, Bytes, Env, String,
+    };
+    use stellar_axelar_std::events::Event;
+    use stellar_axelar_std::{AxelarExpressExecutable, IntoEvent};
+
+    use crate as stellar_axelar_gateway;
+    use crate::executable::{CustomAxelarExecutable, CustomAxelarExpressExecutable};
+
+    #[contract]
+    #[derive(AxelarExpressExecutable)]
+    pub struct ExpressExecutableContract;
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub enum DataKey {
+        Gateway,
+    }
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum ContractError {
+        NotApproved = 1,
+        AlreadyApproved = 2,
+        AlreadyExpressExecuted = 3,
+    }
+
+    #[derive(Debug, PartialEq, Eq, IntoEvent)]
+    pub struct ExecutedEvent {
+        pub source_chain: String,
+        pub message_id: String,
+        pub source_address: String,
+        #[data]
+        pub payload: Bytes,
+    }
+
+    #[derive(Debug, PartialEq, Eq, IntoEvent)]
+    pub struct ExpressExecutorReimbursedEvent {
+        pub express_executor: Address,
+        pub source_chain: String,
+        pub message_id: String,
+    }
+
+    impl CustomAxelarExecutable for ExpressExecutableContract {
+        type Error = ContractError;
+
+        fn __gateway(env: &Env) -> Address {
+            env.storage()
+                .instance()
+                .get(&DataKey::Gateway)
+                .expect("gateway not found")
+        }
+
+        fn __execute(
+            env: &Env,
+            source_chain: String,
+            message_id: String,
+            source_address: String,
+            payload: Bytes,
+        ) -> Result<(), ContractError> {
+            ExecutedEvent {
+                source_chain,
+                message_id,
+                source_address,
+                payload,
+            }
+            .emit(env);
+
+            Ok(())
+        }
+    }
+
+    impl CustomAxelarExpressExecutable for ExpressExecutableContract {
+        fn __reimburse_express_executor(
+            env: &Env,
+            express_executor: Address,
+            source_chain: String,
+            message_id: String,
+            _source_address: String,
+            _payload: Bytes,
+        ) -> Result<(), ContractError> {
+            ExpressExecutorReimbursedEvent {
+                express_executor,
+                source_chain,
+                message_id,
+            }
+            .emit(env);
+
+            Ok(())
+        }
+    }
+
+    #[contractimpl]
+    impl ExpressExecutableContract {
+        pub fn __constructor(env: &Env, gateway: Address) {
+            env.storage().instance().set(&DataKey::Gateway, &gateway);
+        }
+    }
+}
+
+fn test_message(env: &soroban_sdk::Env, contract_address: Address) -> (Message, Bytes) {
+    let payload = bytes!(env, 0xdead);
+
+    (
+        Message {
+            source_chain: String::from_str(env, "ethereum"),
+            message_id: String::from_str(env, "0x0123-1"),
+            source_address: String::from_str(env, "0x4EFE356BEDeCC817cb89B4E9b796dB8bC188DC59"),
+            contract_address,
+            payload_hash: env.crypto().keccak256(&payload).into(),
+        },
+        payload,
+    )
+}
+
+#[test]
+fn express_execute_succeeds() {
+    let (env, _signers, gateway_client) = setup_env(1, 5);
+    let contract_id = env.register(test::ExpressExecutableContract, (gateway_client.address,));
+    let client = test::ExpressExecutableContractClient::new(&env, &contract_id);
+    let (message, payload) = test_message(&env, contract_id);
+    let express_executor = Address::generate(&env);
+
+    assert_auth!(
+        express_executor,
+        client.express_execute(
+            &express_executor,
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &payload,
+        )
+    );
+    goldie::assert!(events::fmt_emitted_event_at_idx::<ExpressExecutedEvent>(
+        &env, -2
+    ));
+
+    assert_eq!(
+        client.express_executor(
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload_hash,
+        ),
+        Some(express_executor)
+    );
+}
+
+fn express_execute_and_approve(
+    env: &soroban_sdk::Env,
+    gateway_client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+) -> (
+    test::ExpressExecutableContractClient<'static>,
+    Message,
+    Bytes,
+) {
+    let contract_id = env.register(
+        test::ExpressExecutableContract,
+        (gateway_client.address.clone(),),
+    );
+    let client = test::ExpressExecutableContractClient::new(env, &contract_id);
+    let (message, payload) = test_message(env, contract_id);
+
+    client.mock_all_auths().express_execute(
+        &Address::generate(env),
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &payload,
+    );
+
+    approve_gateway_messages(env, gateway_client, signers, vec![env, message.clone()]);
+
+    (client, message, payload)
+}
+
+#[test]
+fn execute_fulfills_express_execution() {
+    let (env, signers, gateway_client) = setup_env(1, 5);
+    let (client, message, payload) = express_execute_and_approve(&env, &gateway_client, signers);
+
+    client.execute(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &payload,
+    );
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        ExpressExecutionFulfilledEvent,
+    >(&env, -2));
+
+    assert_eq!(
+        client.express_executor(
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload_hash,
+        ),
+        None
+    );
+    assert!(gateway_client.is_message_executed(&message.source_chain, &message.message_id));
+}
+
+#[test]
+fn execute_reimburses_express_executor() {
+    let (env, signers, gateway_client) = setup_env(1, 5);
+    let (client, message, payload) = express_execute_and_approve(&env, &gateway_client, signers);
+
+    client.execute(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &payload,
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<
+        test::ExpressExecutorReimbursedEvent,
+    >(&env));
+}
+
+#[test]
+fn execute_without_express_execution_runs_execute() {
+    let (env, signers, gateway_client) = setup_env(1, 5);
+    let contract_id = env.register(
+        test::ExpressExecutableContract,
+        (gateway_client.address.clone(),),
+    );
+    let client = test::ExpressExecutableContractClient::new(&env, &contract_id);
+    let (message, payload) = test_message(&env, contract_id);
+
+    approve_gateway_messages(&env, &gateway_client, signers, vec![&env, message.clone()]);
+
+    client.execute(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &payload,
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<test::ExecutedEvent>(&env));
+}
+
+#[test]
+fn express_execute_fails_when_already_express_executed() {
+    let (env, _signers, gateway_client) = setup_env(1, 5);
+    let contract_id = env.register(test::ExpressExecutableContract, (gateway_client.address,));
+    let client = test::ExpressExecutableContractClient::new(&env, &contract_id);
+    let (message, payload) = test_message(&env, contract_id);
+    let express_executor = Address::generate(&env);
+
+    client.mock_all_auths().express_execute(
+        &express_executor,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &payload,
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &Address::generate(&env),
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &payload,
+        ),
+        test::ContractError::AlreadyExpressExecuted
+    );
+}
+
+#[test]
+fn express_execute_fails_when_already_approved() {
+    let (env, signers, gateway_client) = setup_env(1, 5);
+    let contract_id = env.register(
+        test::ExpressExecutableContract,
+        (gateway_client.address.clone(),),
+    );
+    let client = test::ExpressExecutableContractClient::new(&env, &contract_id);
+    let (message, payload) = test_message(&env, contract_id);
+
+    approve_gateway_messages(&env, &gateway_client, signers, vec![&env, message.clone()]);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &Address::generate(&env),
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &payload,
+        ),
+        test::ContractError::AlreadyApproved
+    );
+}
+
+fn approve_messages_root(
+    env: &soroban_sdk::Env,
+    gateway_client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    message: &Message,
+) -> MessageInclusionProof {
+    let (other_message, _) = test_message(env, Address::generate(env));
+    let (root, inclusion_proofs) =
+        generate_messages_merkle_tree(env, &[message.clone(), other_message]);
+
+    let data_hash = get_approve_messages_root_hash(env, root.clone());
+    let proof = generate_proof(env, data_hash, signers);
+    gateway_client.approve_messages_root(&root, &proof);
+
+    inclusion_proofs[0].clone()
+}
+
+#[test]
+fn execute_with_proof_runs_execute() {
+    let (env, signers, gateway_client) = setup_env(1, 5);
+    let contract_id = env.register(
+        test::ExpressExecutableContract,
+        (gateway_client.address.clone(),),
+    );
+    let client = test::ExpressExecutableContractClient::new(&env, &contract_id);
+    let (message, payload) = test_message(&env, contract_id);
+    let inclusion_proof = approve_messages_root(&env, &gateway_client, signers, &message);
+
+    client.execute_with_proof(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &payload,
+        &inclusion_proof,
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<test::ExecutedEvent>(&env));
+
+    assert!(gateway_client.is_message_executed(&message.source_chain, &message.message_id));
+    assert_contract_err!(
+        client.try_execute_with_proof(
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &payload,
+            &inclusion_proof,
+        ),
+        test::ContractError::NotApproved
+    );
+}
+
+#[test]
+fn execute_with_proof_fails_with_invalid_inclusion_proof() {
+    let (env, signers, gateway_client) = setup_env(1, 5);
+    let contract_id = env.register(
+        test::ExpressExecutableContract,
+        (gateway_client.address.clone(),),
+    );
+    let client = test::ExpressExecutableContractClient::new(&env, &contract_id);
+    let (message, _) = test_message(&env, contract_id);
+    let inclusion_proof = approve_messages_root(&env, &gateway_client, signers, &message);
+
+    assert_contract_err!(
+        client.try_execute_with_proof(
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &bytes!(&env, 0xbeef),
+            &inclusion_proof,
+        ),
+        test::ContractError::NotApproved
+    );
+}
+
+#[test]
+fn execute_with_proof_reimburses_express_executor() {
+    let (env, signers, gateway_client) = setup_env(1, 5);
+    let contract_id = env.register(
+        test::ExpressExecutableContract,
+        (gateway_client.address.clone(),),
+    );
+    let client = test::ExpressExecutableContractClient::new(&env, &contract_id);
+    let (message, payload) = test_message(&env, contract_id);
+
+    client.mock_all_auths().express_execute(
+        &Address::generate(&env),
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &payload,
+    );
+
+    let inclusion_proof = approve_messages_root(&env, &gateway_client, signers, &message);
+
+    client.execute_with_proof(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &payload,
+        &inclusion_proof,
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<
+        test::ExpressExecutorReimbursedEvent,
+    >(&env));
+}
+
+#[test]
+fn express_execute_fails_when_approved_with_proof() {
+    let (env, signers, gateway_client) = setup_env(1, 5);
+    let contract_id = env.register(
+        test::ExpressExecutableContract,
+        (gateway_client.address.clone(),),
+    );
+    let client = test::ExpressExecutableContractClient::new(&env, &contract_id);
+    let (message, payload) = test_message(&env, contract_id);
+    let inclusion_proof = approve_messages_root(&env, &gateway_client, signers, &message);
+
+    assert_eq!(
+        gateway_client.approve_message_with_proof(&message, &inclusion_proof),
+        MessageApprovalStatus::NewlyApproved
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_express_execute(
+            &Address::generate(&env),
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &payload,
+        ),
+        test::ContractError::AlreadyApproved
+    );
+}
@@ -1,21 +1,35 @@
-use soroban_sdk::testutils::{Address as _, Events};
-use soroban_sdk::{bytes, vec, Address, BytesN, String};
+use soroban_sdk::testutils::{Address as _, BytesN as _, Events, Ledger};
+use soroban_sdk::{bytes, vec, Address, BytesN, Env, IntoVal, String};
+use stellar_axelar_std::ttl::{self, PERSISTENT_TTL_EXTEND_TO};
 use stellar_axelar_std::{assert_auth, assert_auth_err, assert_contract_err, events};
 
 use super::utils::setup_env;
 use crate::error::ContractError;
 use crate::event::{
-    ContractCalledEvent, MessageApprovedEvent, MessageExecutedEvent, SignersRotatedEvent,
+    BlsPublicKeyRegisteredEvent, ChainAllowlistDisabledEvent, ChainAllowlistEnabledEvent,
+    ContractCalledEvent, DestinationChainRemovedEvent, DestinationChainSetEvent,
+    MessageApprovalExpirySetEvent, MessageApprovalSkippedEvent, MessageApprovedEvent,
+    MessageExecutedEvent, MessageRevokedEvent, MessagesRootApprovedEvent, ScopePausedEvent,
+    ScopeUnpausedEvent, SignersProposalCancelledEvent, SignersProposedEvent, SignersPrunedEvent,
+    SignersRotatedEvent,
 };
 #[cfg(any(test, feature = "testutils"))]
 use crate::testutils::{
-    generate_proof, generate_signers_set, generate_signers_set_with_rng, generate_test_message,
-    generate_test_message_with_rng, get_approve_hash, randint,
+    approve_gateway_messages, generate_aggregate_proof, generate_bls_signers_set,
+    generate_compact_proof, generate_messages_merkle_tree, generate_proof, generate_signers_set,
+    generate_signers_set_with_rng, generate_test_message, generate_test_message_with_rng,
+    get_approve_hash, get_approve_messages_root_hash, get_revoke_hash, randint,
+    register_bls_signers, TestSignerSet, TestSigningKey,
 };
-use crate::types::Message;
+use crate::types::{
+    Message, MessageApprovalStatus, MessageInclusionProof, MessageStatus, PauseScope,
+    SignerPublicKey,
+};
+use crate::{storage, AxelarGatewayClient};
 
 const DESTINATION_CHAIN: &str = "ethereum";
 const DESTINATION_ADDRESS: &str = "0x4EFE356BEDeCC817cb89B4E9b796dB8bC188DC59";
+const MAX_PAYLOAD_LENGTH: u32 = 1024;
 
 fn deterministic_rng() -> rand_chacha::ChaCha20Rng {
     use rand::SeedableRng;
@@ -38,6 +52,63 @@ fn call_contract() {
     goldie::assert!(events::fmt_last_emitted_event::<ContractCalledEvent>(&env));
 }
 
+#[test]
+fn call_contract_increments_outbound_nonce() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let user = Address::generate(&env);
+    let other_user = Address::generate(&env);
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let destination_address = String::from_str(&env, DESTINATION_ADDRESS);
+    let payload = bytes!(&env, 0x1234);
+
+    assert_eq!(client.outbound_nonce(&user), 0);
+
+    let first_id = client.mock_all_auths().call_contract(
+        &user,
+        &destination_chain,
+        &destination_address,
+        &payload,
+    );
+    let second_id = client.mock_all_auths().call_contract(
+        &user,
+        &destination_chain,
+        &destination_address,
+        &payload,
+    );
+    let other_id = client.mock_all_auths().call_contract(
+        &other_user,
+        &destination_chain,
+        &destination_address,
+        &payload,
+    );
+
+    assert_eq!(client.outbound_nonce(&user), 2);
+    assert_eq!(client.outbound_nonce(&other_user), 1);
+    assert_ne!(first_id, second_id);
+    assert_ne!(first_id, other_id);
+    assert_eq!(first_id.len(), 66);
+}
+
+#[test]
+fn call_contract_message_id_matches_event() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let user = Address::generate(&env);
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let destination_address = String::from_str(&env, DESTINATION_ADDRESS);
+    let payload = bytes!(&env, 0x1234);
+
+    let message_id = client.mock_all_auths().call_contract(
+        &user,
+        &destination_chain,
+        &destination_address,
+        &payload,
+    );
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let event_message_id: String = topics.get(5).unwrap().into_val(&env);
+    assert_eq!(event_message_id, message_id);
+}
+
 #[test]
 fn validate_message() {
     let (env, _signers, client) = setup_env(1, 5);
@@ -96,6 +167,33 @@ fn approve_message() {
     assert!(is_approved);
 }
 
+#[test]
+fn approve_message_with_aggregate_proof() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+
+    let bls_signers =
+        generate_bls_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+    register_bls_signers(&env, &client, &bls_signers);
+    let data_hash = bls_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    client.rotate_signers(&bls_signers.signers, &proof, &false);
+
+    let messages = vec![&env, message.clone()];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_aggregate_proof(&env, data_hash, bls_signers);
+
+    client.approve_messages(&messages, &proof.into());
+
+    assert!(client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.contract_address,
+        &message.payload_hash,
+    ));
+}
+
 #[test]
 fn execute_approved_message() {
     let (env, signers, client) = setup_env(1, randint(1, 10));
@@ -139,256 +237,1717 @@ fn execute_approved_message() {
     assert!(is_executed);
 }
 
-#[test]
-fn fail_execute_invalid_proof() {
-    let (env, signers, client) = setup_env(1, randint(1, 10));
-    let (message, _) = generate_test_message(&env);
-
-    let invalid_signers = generate_signers_set(&env, randint(1, 10), signers.domain_separator);
-
-    let messages = vec![&env, message];
-    let data_hash = get_approve_hash(&env, messages.clone());
-    let proof = generate_proof(&env, data_hash, invalid_signers);
-
-    assert_contract_err!(
-        client.try_approve_messages(&messages, &proof),
-        ContractError::InvalidSignersHash
-    );
+fn approve_messages_root(
+    env: &Env,
+    client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    num_messages: u32,
+) -> (std::vec::Vec<Message>, std::vec::Vec<MessageInclusionProof>) {
+    let messages: std::vec::Vec<_> = (0..num_messages)
+        .map(|_| generate_test_message(env).0)
+        .collect();
+    let (root, inclusion_proofs) = generate_messages_merkle_tree(env, &messages);
+
+    let data_hash = get_approve_messages_root_hash(env, root.clone());
+    let proof = generate_proof(env, data_hash, signers);
+    client.approve_messages_root(&root, &proof);
+
+    (messages, inclusion_proofs)
 }
 
 #[test]
-fn approve_messages_fail_empty_messages() {
+fn approve_messages_root_succeeds() {
     let (env, signers, client) = setup_env(1, randint(1, 10));
+    let root = BytesN::from_array(&env, &[1; 32]);
 
-    let messages = soroban_sdk::Vec::new(&env);
-    let data_hash = get_approve_hash(&env, messages.clone());
+    let data_hash = get_approve_messages_root_hash(&env, root.clone());
     let proof = generate_proof(&env, data_hash, signers);
 
-    assert_contract_err!(
-        client.try_approve_messages(&messages, &proof),
-        ContractError::EmptyMessages
-    );
+    assert!(!client.is_messages_root_approved(&root));
+    client.approve_messages_root(&root, &proof);
+    goldie::assert!(events::fmt_last_emitted_event::<MessagesRootApprovedEvent>(
+        &env
+    ));
+
+    assert!(client.is_messages_root_approved(&root));
 }
 
 #[test]
-fn approve_messages_fails_when_contract_is_paused() {
+fn approve_messages_root_fails_when_contract_is_paused() {
     let (env, signers, client) = setup_env(1, randint(1, 10));
+    let root = BytesN::random(&env);
 
     assert_auth!(client.owner(), client.pause());
 
-    let (message, _) = generate_test_message(&env);
-    let messages = vec![&env, message];
-    let data_hash = get_approve_hash(&env, messages.clone());
+    let data_hash = get_approve_messages_root_hash(&env, root.clone());
     let proof = generate_proof(&env, data_hash, signers);
 
     assert_contract_err!(
-        client.try_approve_messages(&messages, &proof),
+        client.try_approve_messages_root(&root, &proof),
         ContractError::ContractPaused
     );
 }
 
 #[test]
-fn approve_messages_skip_duplicate_message() {
+fn execute_messages_with_inclusion_proofs() {
     let (env, signers, client) = setup_env(1, randint(1, 10));
-    let (message, _) = generate_test_message(&env);
-
-    let messages = vec![&env, message];
-    let data_hash = get_approve_hash(&env, messages.clone());
-    let proof = generate_proof(&env, data_hash, signers);
-    client.approve_messages(&messages, &proof);
-
-    client.approve_messages(&messages, &proof);
-    assert_eq!(env.events().all().len(), 0);
+    let (messages, inclusion_proofs) =
+        approve_messages_root(&env, &client, signers, randint(1, 20) as u32);
+
+    for (message, inclusion_proof) in messages.into_iter().zip(inclusion_proofs) {
+        let executed = assert_auth!(
+            message.contract_address,
+            client.validate_message_with_proof(
+                &message.contract_address,
+                &message.source_chain,
+                &message.message_id,
+                &message.source_address,
+                &message.payload_hash,
+                &inclusion_proof,
+            )
+        );
+        assert!(executed);
+        assert!(client.is_message_executed(&message.source_chain, &message.message_id));
+
+        let replayed = client.mock_all_auths().validate_message_with_proof(
+            &message.contract_address,
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload_hash,
+            &inclusion_proof,
+        );
+        assert!(!replayed);
+    }
 }
 
 #[test]
-fn rotate_signers() {
-    let (env, signers, client) = setup_env(1, 5);
-
-    let new_signers = generate_signers_set_with_rng(
-        &env,
-        5,
-        signers.domain_separator.clone(),
-        deterministic_rng(),
-    );
-    let data_hash = new_signers.signers.signers_rotation_hash(&env);
-    let proof = generate_proof(&env, data_hash, signers);
-    let bypass_rotation_delay = false;
+fn validate_message_with_proof_fails_with_unapproved_root() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let messages: std::vec::Vec<_> = (0..3).map(|_| generate_test_message(&env).0).collect();
+    let (_, inclusion_proofs) = generate_messages_merkle_tree(&env, &messages);
+
+    let message = &messages[0];
+    assert!(!client.mock_all_auths().validate_message_with_proof(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+        &inclusion_proofs[0],
+    ));
+}
 
-    client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay);
-    goldie::assert!(events::fmt_last_emitted_event::<SignersRotatedEvent>(&env));
+#[test]
+fn validate_message_with_proof_fails_with_invalid_inclusion_proof() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (messages, inclusion_proofs) = approve_messages_root(&env, &client, signers, 4);
+
+    let message = &messages[0];
+    assert!(!client.mock_all_auths().validate_message_with_proof(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &BytesN::random(&env),
+        &inclusion_proofs[0],
+    ));
+    assert!(!client.mock_all_auths().validate_message_with_proof(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+        &inclusion_proofs[1],
+    ));
+    assert!(!client.is_message_executed(&message.source_chain, &message.message_id));
 }
 
 #[test]
-fn approve_messages_after_rotation() {
-    let (env, signers, client) = setup_env(1, 5);
+fn approve_message_with_proof_succeeds() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (messages, inclusion_proofs) = approve_messages_root(&env, &client, signers, 3);
+    let message = &messages[0];
 
-    let new_signers = generate_signers_set_with_rng(
-        &env,
-        5,
-        signers.domain_separator.clone(),
-        deterministic_rng(),
+    assert_eq!(
+        client.approve_message_with_proof(message, &inclusion_proofs[0]),
+        MessageApprovalStatus::NewlyApproved
     );
-    let data_hash = new_signers.signers.signers_rotation_hash(&env);
-    let proof = generate_proof(&env, data_hash, signers);
-    let bypass_rotation_delay = false;
-
-    client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay);
+    assert_eq!(
+        client.message_status(&message.source_chain, &message.message_id),
+        MessageStatus::Approved(message.hash(&env))
+    );
+    assert!(client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.contract_address,
+        &message.payload_hash,
+    ));
 
-    let (message, _) = generate_test_message_with_rng(&env, deterministic_rng());
-    let messages = vec![&env, message];
-    let data_hash = get_approve_hash(&env, messages.clone());
-    let proof = generate_proof(&env, data_hash, new_signers);
+    assert_eq!(
+        client.approve_message_with_proof(message, &inclusion_proofs[0]),
+        MessageApprovalStatus::AlreadyApproved
+    );
 
-    client.approve_messages(&messages, &proof);
-    goldie::assert!(events::fmt_last_emitted_event::<MessageApprovedEvent>(&env));
+    assert!(client.mock_all_auths().validate_message_with_proof(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+        &inclusion_proofs[0],
+    ));
+    assert_eq!(
+        client.message_status(&message.source_chain, &message.message_id),
+        MessageStatus::Executed
+    );
 }
 
 #[test]
-fn rotate_signers_bypass_rotation_delay() {
-    let (env, signers, client) = setup_env(1, 5);
-    let new_signers = generate_signers_set_with_rng(
-        &env,
-        5,
-        signers.domain_separator.clone(),
-        deterministic_rng(),
+fn approve_message_with_proof_fails_with_invalid_inclusion_proof() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (messages, inclusion_proofs) = approve_messages_root(&env, &client, signers, 4);
+
+    assert_contract_err!(
+        client.try_approve_message_with_proof(&messages[0], &inclusion_proofs[1]),
+        ContractError::InvalidMessageInclusionProof
     );
-    let data_hash = new_signers.signers.signers_rotation_hash(&env);
-    let proof = generate_proof(&env, data_hash, signers);
-    let bypass_rotation_delay = true;
 
-    assert_auth!(
-        client.operator(),
-        client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay)
+    let unapproved_messages: std::vec::Vec<_> =
+        (0..2).map(|_| generate_test_message(&env).0).collect();
+    let (_, unapproved_inclusion_proofs) =
+        generate_messages_merkle_tree(&env, &unapproved_messages);
+    assert_contract_err!(
+        client.try_approve_message_with_proof(
+            &unapproved_messages[0],
+            &unapproved_inclusion_proofs[0]
+        ),
+        ContractError::InvalidMessageInclusionProof
     );
-    goldie::assert!(events::fmt_last_emitted_event::<SignersRotatedEvent>(&env));
 }
 
 #[test]
-fn rotate_signers_bypass_rotation_delay_unauthorized() {
-    let (env, signers, client) = setup_env(1, 5);
+fn approve_message_with_proof_expires_with_root() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let expiry = 3600;
 
-    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    client.mock_all_auths().set_message_approval_expiry(&expiry);
+    let (messages, inclusion_proofs) = approve_messages_root(&env, &client, signers, 2);
+    let message = &messages[0];
 
-    let data_hash = new_signers.signers.signers_rotation_hash(&env);
-    let proof = generate_proof(&env, data_hash, signers);
-    let bypass_rotation_delay = true;
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + expiry);
+    client.approve_message_with_proof(message, &inclusion_proofs[0]);
 
-    assert_auth_err!(
-        client.owner(),
-        client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay)
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    assert_eq!(
+        client.message_status(&message.source_chain, &message.message_id),
+        MessageStatus::NotApproved
     );
-
-    let not_operator = Address::generate(&env);
-    assert_auth_err!(
-        not_operator,
-        client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay)
+    assert_contract_err!(
+        client.try_approve_message_with_proof(message, &inclusion_proofs[0]),
+        ContractError::InvalidMessageInclusionProof
     );
 }
 
 #[test]
-fn rotate_signers_fail_not_latest_signers() {
-    let (env, signers, client) = setup_env(1, 5);
+fn extend_message_ttl() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    approve_gateway_messages(&env, &client, signers, vec![&env, message.clone()]);
 
-    let bypass_rotation_delay = false;
+    client.extend_message_ttl(&message.source_chain, &message.message_id);
+}
 
-    let first_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
-    let data_hash = first_signers.signers.signers_rotation_hash(&env);
-    let proof = generate_proof(&env, data_hash, signers.clone());
-    client.rotate_signers(&first_signers.signers, &proof, &bypass_rotation_delay);
+#[test]
+fn extend_message_ttl_extends_approval_timestamp() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    let expiry = 365 * 24 * 3600;
+    client.mock_all_auths().set_message_approval_expiry(&expiry);
+    approve_gateway_messages(&env, &client, signers, vec![&env, message.clone()]);
+
+    let advance_past_threshold = |env: &Env| {
+        env.as_contract(&client.address, || ttl::extend_instance_ttl(env));
+        env.ledger()
+            .with_mut(|ledger| ledger.sequence_number += PERSISTENT_TTL_EXTEND_TO - 1);
+    };
+
+    advance_past_threshold(&env);
+    client.extend_message_ttl(&message.source_chain, &message.message_id);
+    advance_past_threshold(&env);
+
+    assert!(client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.contract_address,
+        &message.payload_hash,
+    ));
+}
 
-    let second_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
-    let data_hash = second_signers.signers.signers_rotation_hash(&env);
-    let proof = generate_proof(&env, data_hash, signers);
+#[test]
+fn extend_message_ttl_fails_for_unknown_message() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
 
     assert_contract_err!(
-        client.try_rotate_signers(&second_signers.signers, &proof, &bypass_rotation_delay),
-        ContractError::NotLatestSigners
+        client.try_extend_message_ttl(&message.source_chain, &message.message_id),
+        ContractError::MessageApprovalNotFound
     );
 }
 
 #[test]
-fn transfer_operatorship_unauthorized() {
-    let (env, _, client) = setup_env(1, randint(1, 10));
-    let not_operator = Address::generate(&env);
+fn message_statuses() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (approved_message, _) = generate_test_message(&env);
+    let (executed_message, _) = generate_test_message(&env);
+    let (unknown_message, _) = generate_test_message(&env);
 
-    assert_auth_err!(
-        client.owner(),
-        client.transfer_operatorship(&client.owner())
+    approve_gateway_messages(
+        &env,
+        &client,
+        signers,
+        vec![&env, approved_message.clone(), executed_message.clone()],
+    );
+    assert!(client.mock_all_auths().validate_message(
+        &executed_message.contract_address,
+        &executed_message.source_chain,
+        &executed_message.message_id,
+        &executed_message.source_address,
+        &executed_message.payload_hash,
+    ));
+
+    let statuses = client.message_statuses(&vec![
+        &env,
+        (
+            approved_message.source_chain.clone(),
+            approved_message.message_id.clone(),
+        ),
+        (
+            executed_message.source_chain.clone(),
+            executed_message.message_id,
+        ),
+        (
+            unknown_message.source_chain.clone(),
+            unknown_message.message_id,
+        ),
+    ]);
+
+    assert_eq!(
+        statuses,
+        vec![
+            &env,
+            MessageStatus::Approved(approved_message.hash(&env)),
+            MessageStatus::Executed,
+            MessageStatus::NotApproved,
+        ]
+    );
+    assert_eq!(
+        client.message_status(&approved_message.source_chain, &approved_message.message_id),
+        MessageStatus::Approved(approved_message.hash(&env))
     );
-    assert_auth_err!(not_operator, client.transfer_operatorship(&not_operator));
 }
 
 #[test]
-fn transfer_ownership_unauthorized() {
-    let (env, _, client) = setup_env(1, randint(1, 10));
+fn message_status_is_not_approved_after_approval_expiry() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    let expiry = 3600;
 
-    let new_owner = Address::generate(&env);
+    client.mock_all_auths().set_message_approval_expiry(&expiry);
+    approve_gateway_messages(&env, &client, signers, vec![&env, message.clone()]);
 
-    assert_auth_err!(new_owner, client.transfer_ownership(&new_owner));
-    assert_auth_err!(
-        client.operator(),
-        client.transfer_ownership(&client.operator())
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + expiry + 1);
+    assert_eq!(
+        client.message_status(&message.source_chain, &message.message_id),
+        MessageStatus::NotApproved
     );
 }
 
 #[test]
-fn epoch_by_signers_hash() {
-    let (env, signers, client) = setup_env(1, 5);
-
-    let bypass_rotation_delay = false;
+fn approve_messages_reapproves_expired_approval() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    let expiry = 3600;
 
-    let first_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
-    let data_hash = first_signers.signers.signers_rotation_hash(&env);
-    let proof = generate_proof(&env, data_hash, signers);
+    client.mock_all_auths().set_message_approval_expiry(&expiry);
+    approve_gateway_messages(&env, &client, signers.clone(), vec![&env, message.clone()]);
 
-    client.rotate_signers(&first_signers.signers, &proof, &bypass_rotation_delay);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + expiry + 1);
 
+    let messages = vec![&env, message.clone()];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
     assert_eq!(
-        client.epoch_by_signers_hash(&first_signers.signers.hash(&env)),
-        client.epoch()
+        client.approve_messages(&messages, &proof),
+        vec![&env, MessageApprovalStatus::NewlyApproved]
+    );
+    assert_eq!(
+        client.message_status(&message.source_chain, &message.message_id),
+        MessageStatus::Approved(message.hash(&env))
     );
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + expiry);
+    assert!(client.mock_all_auths().validate_message(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+    ));
 }
 
 #[test]
-fn epoch_by_signers_hash_fail_invalid_signers() {
-    let (env, _, client) = setup_env(1, 5);
-    let signers_hash = BytesN::<32>::from_array(&env, &[1; 32]);
+fn set_message_approval_expiry() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let expiry = 3600;
 
-    assert_contract_err!(
-        client.try_epoch_by_signers_hash(&signers_hash),
-        ContractError::InvalidSignersHash
+    assert_eq!(client.message_approval_expiry(), 0);
+
+    assert_auth!(client.owner(), client.set_message_approval_expiry(&expiry));
+    goldie::assert!(events::fmt_last_emitted_event::<
+        MessageApprovalExpirySetEvent,
+    >(&env));
+
+    assert_eq!(client.message_approval_expiry(), expiry);
+
+    let disabled_expiry = 0u64;
+    assert_auth!(
+        client.owner(),
+        client.set_message_approval_expiry(&disabled_expiry)
     );
+    assert_eq!(client.message_approval_expiry(), 0);
 }
 
 #[test]
-fn signers_hash_by_epoch() {
-    let (env, signers, client) = setup_env(1, 5);
+fn set_message_approval_expiry_unauthorized() {
+    let (_env, _signers, client) = setup_env(1, randint(1, 10));
 
-    let bypass_rotation_delay = false;
+    let expiry = 3600u64;
+    assert_auth_err!(
+        client.operator(),
+        client.set_message_approval_expiry(&expiry)
+    );
+}
 
-    let first_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
-    let data_hash = first_signers.signers.signers_rotation_hash(&env);
-    let proof = generate_proof(&env, data_hash, signers);
+#[test]
+fn validate_message_fails_after_approval_expiry() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    let expiry = 3600;
+
+    client.mock_all_auths().set_message_approval_expiry(&expiry);
+    approve_gateway_messages(&env, &client, signers, vec![&env, message.clone()]);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + expiry);
+    assert!(client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.contract_address,
+        &message.payload_hash,
+    ));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    assert!(!client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.contract_address,
+        &message.payload_hash,
+    ));
+    assert!(!client.mock_all_auths().validate_message(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+    ));
+}
 
-    client.rotate_signers(&first_signers.signers, &proof, &bypass_rotation_delay);
-    let epoch = client.epoch();
+#[test]
+fn approvals_before_expiry_is_set_do_not_expire() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    let expiry = 3600;
+
+    approve_gateway_messages(&env, &client, signers, vec![&env, message.clone()]);
+    client.mock_all_auths().set_message_approval_expiry(&expiry);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + expiry + 1);
+    assert!(client.mock_all_auths().validate_message(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+    ));
+}
 
-    assert_eq!(
-        client.signers_hash_by_epoch(&epoch),
-        first_signers.signers.hash(&env)
-    );
+#[test]
+fn validate_message_with_proof_fails_after_root_approval_expiry() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let expiry = 3600;
+
+    client.mock_all_auths().set_message_approval_expiry(&expiry);
+    let (messages, inclusion_proofs) = approve_messages_root(&env, &client, signers, 2);
+    assert!(client.is_messages_root_approved(&inclusion_proofs[0].root));
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + expiry + 1);
+    assert!(!client.is_messages_root_approved(&inclusion_proofs[0].root));
+
+    let message = &messages[0];
+    assert!(!client.mock_all_auths().validate_message_with_proof(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+        &inclusion_proofs[0],
+    ));
 }
 
 #[test]
-fn signers_hash_by_epoch_fail_invalid_epoch() {
-    let (_, _, client) = setup_env(1, 5);
+fn revoke_messages() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message_with_rng(&env, deterministic_rng());
+    let messages = vec![&env, message.clone()];
+    approve_gateway_messages(&env, &client, signers.clone(), messages.clone());
+
+    let data_hash = get_revoke_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    client.revoke_messages(&messages, &proof);
+    goldie::assert!(events::fmt_last_emitted_event::<MessageRevokedEvent>(&env));
+
+    assert!(client.is_message_revoked(&message.source_chain, &message.message_id));
+    assert_eq!(
+        client.message_status(&message.source_chain, &message.message_id),
+        MessageStatus::Revoked
+    );
+    assert!(!client.is_message_approved(
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.contract_address,
+        &message.payload_hash,
+    ));
+    assert!(!client.mock_all_auths().validate_message(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+    ));
+}
+
+#[test]
+fn revoke_messages_skips_executed_message() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    let messages = vec![&env, message.clone()];
+    approve_gateway_messages(&env, &client, signers.clone(), messages.clone());
+
+    assert!(client.mock_all_auths().validate_message(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+    ));
+
+    let data_hash = get_revoke_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    client.revoke_messages(&messages, &proof);
+    assert_eq!(env.events().all().len(), 0);
+
+    assert!(!client.is_message_revoked(&message.source_chain, &message.message_id));
+    assert!(client.is_message_executed(&message.source_chain, &message.message_id));
+}
+
+#[test]
+fn revoke_messages_fails_with_empty_messages() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+
+    let messages = soroban_sdk::Vec::new(&env);
+    let data_hash = get_revoke_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_revoke_messages(&messages, &proof),
+        ContractError::EmptyMessages
+    );
+}
+
+#[test]
+#[should_panic(expected = "failed ED25519 verification")]
+fn revoke_messages_fails_with_approval_proof() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    let messages = vec![&env, message];
+
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    client.revoke_messages(&messages, &proof);
+}
+
+#[test]
+fn approve_messages_skips_revoked_message() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    let messages = vec![&env, message];
+
+    let data_hash = get_revoke_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers.clone());
+    client.revoke_messages(&messages, &proof);
+
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    assert_eq!(
+        client.approve_messages(&messages, &proof),
+        vec![&env, MessageApprovalStatus::AlreadyRevoked]
+    );
+}
+
+#[test]
+fn validate_message_with_proof_fails_for_revoked_message() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (messages, inclusion_proofs) = approve_messages_root(&env, &client, signers.clone(), 3);
+    let message = &messages[0];
+
+    let revoked_messages = vec![&env, message.clone()];
+    let data_hash = get_revoke_hash(&env, revoked_messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    client.revoke_messages(&revoked_messages, &proof);
+
+    assert!(!client.mock_all_auths().validate_message_with_proof(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+        &inclusion_proofs[0],
+    ));
+}
+
+#[test]
+fn fail_execute_invalid_proof() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+
+    let invalid_signers = generate_signers_set(&env, randint(1, 10), signers.domain_separator);
+
+    let messages = vec![&env, message];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, invalid_signers);
+
+    assert_contract_err!(
+        client.try_approve_messages(&messages, &proof),
+        ContractError::InvalidSignersHash
+    );
+}
+
+#[test]
+fn approve_messages_fail_empty_messages() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+
+    let messages = soroban_sdk::Vec::new(&env);
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_approve_messages(&messages, &proof),
+        ContractError::EmptyMessages
+    );
+}
+
+#[test]
+fn approve_messages_fails_when_contract_is_paused() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+
+    assert_auth!(client.owner(), client.pause());
+
+    let (message, _) = generate_test_message(&env);
+    let messages = vec![&env, message];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_approve_messages(&messages, &proof),
+        ContractError::ContractPaused
+    );
+}
+
+#[test]
+fn approve_messages_skip_duplicate_message() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+
+    let messages = vec![&env, message];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    client.approve_messages(&messages, &proof);
+
+    let statuses = client.approve_messages(&messages, &proof);
+    assert_eq!(statuses, vec![&env, MessageApprovalStatus::AlreadyApproved]);
+    assert_eq!(env.events().all().len(), 1);
+}
+
+#[test]
+fn approve_messages_skip_executed_message() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message_with_rng(&env, deterministic_rng());
+
+    let messages = vec![&env, message.clone()];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    client.approve_messages(&messages, &proof);
+
+    assert_auth!(
+        message.contract_address,
+        client.validate_message(
+            &message.contract_address,
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload_hash,
+        )
+    );
+
+    let statuses = client.approve_messages(&messages, &proof);
+    assert_eq!(statuses, vec![&env, MessageApprovalStatus::AlreadyExecuted]);
+    goldie::assert!(events::fmt_last_emitted_event::<MessageApprovalSkippedEvent>(&env));
+}
+
+#[test]
+fn approve_messages_returns_statuses_in_order() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (first_message, _) = generate_test_message(&env);
+    let (second_message, _) = generate_test_message(&env);
+
+    let messages = vec![&env, first_message.clone()];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers.clone());
+    client.approve_messages(&messages, &proof);
+
+    let messages = vec![&env, first_message, second_message];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    let statuses = client.approve_messages(&messages, &proof);
+    assert_eq!(
+        statuses,
+        vec![
+            &env,
+            MessageApprovalStatus::AlreadyApproved,
+            MessageApprovalStatus::NewlyApproved
+        ]
+    );
+}
+
+#[test]
+fn rotate_signers() {
+    let (env, signers, client) = setup_env(1, 5);
+
+    let new_signers = generate_signers_set_with_rng(
+        &env,
+        5,
+        signers.domain_separator.clone(),
+        deterministic_rng(),
+    );
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    let bypass_rotation_delay = false;
+
+    client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay);
+    goldie::assert!(events::fmt_last_emitted_event::<SignersRotatedEvent>(&env));
+}
+
+#[test]
+fn approve_messages_after_rotation() {
+    let (env, signers, client) = setup_env(1, 5);
+
+    let new_signers = generate_signers_set_with_rng(
+        &env,
+        5,
+        signers.domain_separator.clone(),
+        deterministic_rng(),
+    );
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    let bypass_rotation_delay = false;
+
+    client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay);
+
+    let (message, _) = generate_test_message_with_rng(&env, deterministic_rng());
+    let messages = vec![&env, message];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, new_signers);
+
+    client.approve_messages(&messages, &proof);
+    goldie::assert!(events::fmt_last_emitted_event::<MessageApprovedEvent>(&env));
+}
+
+#[test]
+fn rotate_signers_bypass_rotation_delay() {
+    let (env, signers, client) = setup_env(1, 5);
+    let new_signers = generate_signers_set_with_rng(
+        &env,
+        5,
+        signers.domain_separator.clone(),
+        deterministic_rng(),
+    );
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    let bypass_rotation_delay = true;
+
+    assert_auth!(
+        client.operator(),
+        client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<SignersRotatedEvent>(&env));
+}
+
+#[test]
+fn rotate_signers_bypass_rotation_delay_unauthorized() {
+    let (env, signers, client) = setup_env(1, 5);
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    let bypass_rotation_delay = true;
+
+    assert_auth_err!(
+        client.owner(),
+        client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay)
+    );
+
+    let not_operator = Address::generate(&env);
+    assert_auth_err!(
+        not_operator,
+        client.rotate_signers(&new_signers.signers, &proof, &bypass_rotation_delay)
+    );
+}
+
+#[test]
+fn rotate_signers_with_compact_proof() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+
+    let new_signers = generate_signers_set(&env, randint(1, 10), signers.domain_separator.clone());
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_compact_proof(&env, data_hash, signers);
+
+    client.rotate_signers(&new_signers.signers, &proof.into(), &false);
+
+    assert_eq!(
+        client.epoch_by_signers_hash(&new_signers.signers.hash(&env)),
+        client.epoch()
+    );
+}
+
+#[test]
+fn rotate_signers_fail_not_latest_signers() {
+    let (env, signers, client) = setup_env(1, 5);
+
+    let bypass_rotation_delay = false;
+
+    let first_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = first_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers.clone());
+    client.rotate_signers(&first_signers.signers, &proof, &bypass_rotation_delay);
+
+    let second_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = second_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_rotate_signers(&second_signers.signers, &proof, &bypass_rotation_delay),
+        ContractError::NotLatestSigners
+    );
+}
+
+#[test]
+fn transfer_operatorship_unauthorized() {
+    let (env, _, client) = setup_env(1, randint(1, 10));
+    let not_operator = Address::generate(&env);
+
+    assert_auth_err!(
+        client.owner(),
+        client.transfer_operatorship(&client.owner())
+    );
+    assert_auth_err!(not_operator, client.transfer_operatorship(&not_operator));
+}
+
+#[test]
+fn transfer_ownership_unauthorized() {
+    let (env, _, client) = setup_env(1, randint(1, 10));
+
+    let new_owner = Address::generate(&env);
+
+    assert_auth_err!(new_owner, client.transfer_ownership(&new_owner));
+    assert_auth_err!(
+        client.operator(),
+        client.transfer_ownership(&client.operator())
+    );
+}
+
+#[test]
+fn epoch_by_signers_hash() {
+    let (env, signers, client) = setup_env(1, 5);
+
+    let bypass_rotation_delay = false;
+
+    let first_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = first_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    client.rotate_signers(&first_signers.signers, &proof, &bypass_rotation_delay);
+
+    assert_eq!(
+        client.epoch_by_signers_hash(&first_signers.signers.hash(&env)),
+        client.epoch()
+    );
+}
+
+#[test]
+fn epoch_by_signers_hash_fail_invalid_signers() {
+    let (env, _, client) = setup_env(1, 5);
+    let signers_hash = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    assert_contract_err!(
+        client.try_epoch_by_signers_hash(&signers_hash),
+        ContractError::InvalidSignersHash
+    );
+}
+
+#[test]
+fn signers_hash_by_epoch() {
+    let (env, signers, client) = setup_env(1, 5);
+
+    let bypass_rotation_delay = false;
+
+    let first_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = first_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    client.rotate_signers(&first_signers.signers, &proof, &bypass_rotation_delay);
+    let epoch = client.epoch();
+
+    assert_eq!(
+        client.signers_hash_by_epoch(&epoch),
+        first_signers.signers.hash(&env)
+    );
+}
+
+#[test]
+fn signers_hash_by_epoch_fail_invalid_epoch() {
+    let (_, _, client) = setup_env(1, 5);
     let invalid_epoch = 43u64;
 
     assert_contract_err!(
-        client.try_signers_hash_by_epoch(&invalid_epoch),
-        ContractError::InvalidEpoch
+        client.try_signers_hash_by_epoch(&invalid_epoch),
+        ContractError::InvalidEpoch
+    );
+}
+
+#[test]
+fn signers_by_epoch() {
+    let (env, signers, client) = setup_env(1, 5);
+
+    let first_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = first_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers.clone());
+
+    client.rotate_signers(&first_signers.signers, &proof, &false);
+    let epoch = client.epoch();
+
+    assert_eq!(client.signers_by_epoch(&(epoch - 1)), signers.signers);
+    assert_eq!(client.signers_by_epoch(&epoch), first_signers.signers);
+    assert_eq!(client.current_signers(), first_signers.signers);
+}
+
+#[test]
+fn signers_by_epoch_fail_invalid_epoch() {
+    let (_, _, client) = setup_env(1, 5);
+    let invalid_epoch = 43u64;
+
+    assert_contract_err!(
+        client.try_signers_by_epoch(&invalid_epoch),
+        ContractError::InvalidEpoch
+    );
+}
+
+#[test]
+fn is_signer() {
+    let (env, signers, client) = setup_env(1, 5);
+    let initial_epoch = client.epoch();
+    let signer = signers.signers.signers.get_unchecked(0).signer;
+
+    assert!(client.is_signer(&initial_epoch, &signer));
+    assert!(!client.is_signer(&(initial_epoch + 1), &signer));
+
+    let not_signer = generate_signers_set(&env, 1, signers.domain_separator)
+        .signers
+        .signers
+        .get_unchecked(0)
+        .signer;
+    assert!(!client.is_signer(&initial_epoch, &not_signer));
+}
+
+#[test]
+fn is_signer_false_outside_retention_window() {
+    let previous_signers_retention = 1;
+    let (env, signers, client) = setup_env(previous_signers_retention, 5);
+    let initial_epoch = client.epoch();
+    let signer = signers.signers.signers.get_unchecked(0).signer;
+
+    let mut latest_signers = signers;
+    for _ in 0..=previous_signers_retention {
+        let new_signers = generate_signers_set(&env, 5, latest_signers.domain_separator.clone());
+        let data_hash = new_signers.signers.signers_rotation_hash(&env);
+        let proof = generate_proof(&env, data_hash, latest_signers);
+        client.rotate_signers(&new_signers.signers, &proof, &false);
+
+        latest_signers = new_signers;
+    }
+
+    assert!(!client.is_signer(&initial_epoch, &signer));
+}
+
+#[test]
+fn register_bls_public_key() {
+    let (env, _, client) = setup_env(1, randint(1, 10));
+
+    let signing_key = TestSigningKey::Bls12381([1; 32]);
+    let SignerPublicKey::Bls12381(public_key) = signing_key.public_key(&env) else {
+        panic!("not a BLS12-381 signer");
+    };
+    let proof_of_possession = signing_key.bls_proof_of_possession(&env);
+
+    assert!(!client.is_bls_public_key_registered(&public_key));
+
+    client.register_bls_public_key(&public_key, &proof_of_possession);
+    goldie::assert!(events::fmt_last_emitted_event::<BlsPublicKeyRegisteredEvent>(&env));
+
+    assert!(client.is_bls_public_key_registered(&public_key));
+}
+
+#[test]
+fn prune_signers() {
+    let (env, signers, client) = setup_env(0, randint(1, 10));
+
+    let first_signers = generate_signers_set_with_rng(
+        &env,
+        5,
+        signers.domain_separator.clone(),
+        deterministic_rng(),
+    );
+    let data_hash = first_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    client.rotate_signers(&first_signers.signers, &proof, &false);
+
+    let second_signers = generate_signers_set(&env, 5, first_signers.domain_separator.clone());
+    let data_hash = second_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, first_signers.clone());
+    client.rotate_signers(&second_signers.signers, &proof, &false);
+
+    let expired_epoch = client.epoch() - 1;
+    client.prune_signers(&vec![&env, expired_epoch]);
+    goldie::assert!(events::fmt_last_emitted_event::<SignersPrunedEvent>(&env));
+
+    assert_contract_err!(
+        client.try_signers_hash_by_epoch(&expired_epoch),
+        ContractError::InvalidEpoch
+    );
+    assert_contract_err!(
+        client.try_signers_by_epoch(&expired_epoch),
+        ContractError::InvalidEpoch
+    );
+    assert_contract_err!(
+        client.try_epoch_by_signers_hash(&first_signers.signers.hash(&env)),
+        ContractError::InvalidSignersHash
+    );
+}
+
+#[test]
+fn rotate_signers_succeeds_with_pruned_signers() {
+    let (env, signers, client) = setup_env(0, randint(1, 10));
+
+    let first_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = first_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    client.rotate_signers(&first_signers.signers, &proof, &false);
+
+    let second_signers = generate_signers_set(&env, 5, first_signers.domain_separator.clone());
+    let data_hash = second_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, first_signers.clone());
+    client.rotate_signers(&second_signers.signers, &proof, &false);
+
+    client.prune_signers(&vec![&env, client.epoch() - 1]);
+
+    let data_hash = first_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, second_signers);
+    client.rotate_signers(&first_signers.signers, &proof, &false);
+
+    assert_eq!(
+        client.epoch_by_signers_hash(&first_signers.signers.hash(&env)),
+        client.epoch()
+    );
+}
+
+#[test]
+fn prune_signers_fails_within_retention_window() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    client.rotate_signers(&new_signers.signers, &proof, &false);
+
+    assert_contract_err!(
+        client.try_prune_signers(&vec![&env, client.epoch() - 1]),
+        ContractError::SignersNotExpired
+    );
+    assert_contract_err!(
+        client.try_prune_signers(&vec![&env, client.epoch()]),
+        ContractError::SignersNotExpired
+    );
+}
+
+#[test]
+fn prune_signers_fails_with_invalid_epoch() {
+    let (env, _, client) = setup_env(0, randint(1, 10));
+    let invalid_epoch = 43u64;
+
+    assert_contract_err!(
+        client.try_prune_signers(&vec![&env, invalid_epoch]),
+        ContractError::InvalidEpoch
+    );
+}
+
+fn propose_signers_with_activation(
+    env: &Env,
+    client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    activation_timestamp: u64,
+) -> TestSignerSet {
+    let new_signers = generate_signers_set_with_rng(
+        env,
+        5,
+        signers.domain_separator.clone(),
+        deterministic_rng(),
+    );
+    let data_hash = new_signers
+        .signers
+        .signers_proposal_hash(env, activation_timestamp);
+    let proof = generate_proof(env, data_hash, signers);
+
+    client.propose_signers(&new_signers.signers, &proof, &activation_timestamp);
+
+    new_signers
+}
+
+#[test]
+fn propose_signers() {
+    let (env, signers, client) = setup_env(1, 5);
+    let activation_timestamp = 1000;
+
+    let new_signers = propose_signers_with_activation(&env, &client, signers, activation_timestamp);
+    goldie::assert!(events::fmt_last_emitted_event::<SignersProposedEvent>(&env));
+
+    let pending_signers = client.pending_signers();
+    assert_eq!(pending_signers.signers, new_signers.signers);
+    assert_eq!(pending_signers.activation_timestamp, activation_timestamp);
+    assert_eq!(pending_signers.epoch, client.epoch());
+}
+
+#[test]
+fn propose_signers_fails_with_pending_signers() {
+    let (env, signers, client) = setup_env(1, 5);
+    propose_signers_with_activation(&env, &client, signers.clone(), 1000);
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = new_signers.signers.signers_proposal_hash(&env, 1000);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_propose_signers(&new_signers.signers, &proof, &1000),
+        ContractError::PendingSignersExists
+    );
+}
+
+#[test]
+fn propose_signers_fails_with_insufficient_rotation_delay() {
+    let (env, signers, client) = setup_env(1, 5);
+    env.ledger().set_timestamp(1000);
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let activation_timestamp = env.ledger().timestamp() - 1;
+    let data_hash = new_signers
+        .signers
+        .signers_proposal_hash(&env, activation_timestamp);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_propose_signers(&new_signers.signers, &proof, &activation_timestamp),
+        ContractError::InsufficientRotationDelay
+    );
+}
+
+#[test]
+fn propose_signers_fails_with_previous_signers() {
+    let (env, signers, client) = setup_env(1, 5);
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers.clone());
+    client.rotate_signers(&new_signers.signers, &proof, &false);
+
+    let proposed_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = proposed_signers.signers.signers_proposal_hash(&env, 1000);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_propose_signers(&proposed_signers.signers, &proof, &1000),
+        ContractError::NotLatestSigners
+    );
+}
+
+#[test]
+fn execute_rotation() {
+    let (env, signers, client) = setup_env(1, 5);
+    let activation_timestamp = 1000;
+    let new_signers = propose_signers_with_activation(&env, &client, signers, activation_timestamp);
+
+    env.ledger().set_timestamp(activation_timestamp);
+
+    client.execute_rotation();
+    goldie::assert!(events::fmt_last_emitted_event::<SignersRotatedEvent>(&env));
+
+    assert_eq!(client.current_signers(), new_signers.signers);
+    assert_contract_err!(
+        client.try_pending_signers(),
+        ContractError::NoPendingSigners
+    );
+}
+
+#[test]
+fn execute_rotation_fails_before_activation() {
+    let (env, signers, client) = setup_env(1, 5);
+    let activation_timestamp = 1000;
+    propose_signers_with_activation(&env, &client, signers, activation_timestamp);
+
+    env.ledger().set_timestamp(activation_timestamp - 1);
+
+    assert_contract_err!(
+        client.try_execute_rotation(),
+        ContractError::PendingSignersNotActive
+    );
+}
+
+#[test]
+fn execute_rotation_fails_without_pending_signers() {
+    let (_env, _signers, client) = setup_env(1, 5);
+
+    assert_contract_err!(
+        client.try_execute_rotation(),
+        ContractError::NoPendingSigners
+    );
+}
+
+#[test]
+fn rotate_signers_cancels_pending_signers() {
+    let (env, signers, client) = setup_env(1, 5);
+    let activation_timestamp = 1000;
+    propose_signers_with_activation(&env, &client, signers.clone(), activation_timestamp);
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers);
+    client.rotate_signers(&new_signers.signers, &proof, &false);
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        SignersProposalCancelledEvent,
+    >(&env, -2));
+
+    assert_contract_err!(
+        client.try_pending_signers(),
+        ContractError::NoPendingSigners
+    );
+
+    let proposed_signers = generate_signers_set(&env, 5, new_signers.domain_separator.clone());
+    let data_hash = proposed_signers
+        .signers
+        .signers_proposal_hash(&env, activation_timestamp);
+    let proof = generate_proof(&env, data_hash, new_signers);
+    client.propose_signers(&proposed_signers.signers, &proof, &activation_timestamp);
+}
+
+#[test]
+fn cancel_rotation() {
+    let (env, signers, client) = setup_env(1, 5);
+    let activation_timestamp = 1000;
+    propose_signers_with_activation(&env, &client, signers, activation_timestamp);
+
+    assert_auth!(client.operator(), client.cancel_rotation());
+    goldie::assert!(events::fmt_last_emitted_event::<
+        SignersProposalCancelledEvent,
+    >(&env));
+
+    assert_contract_err!(
+        client.try_pending_signers(),
+        ContractError::NoPendingSigners
+    );
+}
+
+#[test]
+fn cancel_rotation_unauthorized() {
+    let (env, signers, client) = setup_env(1, 5);
+    propose_signers_with_activation(&env, &client, signers, 1000);
+
+    assert_auth_err!(client.owner(), client.cancel_rotation());
+}
+
+#[test]
+fn cancel_rotation_fails_after_activation() {
+    let (env, signers, client) = setup_env(1, 5);
+    let activation_timestamp = 1000;
+    propose_signers_with_activation(&env, &client, signers, activation_timestamp);
+
+    env.ledger().set_timestamp(activation_timestamp);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_cancel_rotation(),
+        ContractError::PendingSignersActive
+    );
+}
+
+#[test]
+fn cancel_rotation_succeeds_with_stale_pending_signers_after_activation() {
+    let (env, signers, client) = setup_env(1, 5);
+    let activation_timestamp = 1000;
+    propose_signers_with_activation(&env, &client, signers, activation_timestamp);
+
+    env.as_contract(&client.address, || {
+        let mut pending_signers = storage::pending_signers(&env);
+        pending_signers.epoch -= 1;
+        storage::set_pending_signers(&env, &pending_signers);
+    });
+
+    env.ledger().set_timestamp(activation_timestamp);
+
+    client.mock_all_auths().cancel_rotation();
+
+    assert_contract_err!(
+        client.try_pending_signers(),
+        ContractError::NoPendingSigners
+    );
+}
+
+#[test]
+fn propose_signers_fails_with_activation_timestamp_overflow() {
+    let (env, signers, client) = setup_env(1, 5);
+    env.as_contract(&client.address, || {
+        storage::set_minimum_rotation_delay(&env, &1);
+    });
+    env.ledger().set_timestamp(u64::MAX);
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = new_signers.signers.signers_proposal_hash(&env, u64::MAX);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_contract_err!(
+        client.try_propose_signers(&new_signers.signers, &proof, &u64::MAX),
+        ContractError::InsufficientRotationDelay
+    );
+}
+
+#[test]
+fn propose_signers_fails_with_cancelled_proposal() {
+    let (env, signers, client) = setup_env(1, 5);
+    let activation_timestamp = 1000;
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = new_signers
+        .signers
+        .signers_proposal_hash(&env, activation_timestamp);
+    let proof = generate_proof(&env, data_hash, signers);
+
+    client.propose_signers(&new_signers.signers, &proof, &activation_timestamp);
+    client.mock_all_auths().cancel_rotation();
+
+    assert_contract_err!(
+        client.try_propose_signers(&new_signers.signers, &proof, &activation_timestamp),
+        ContractError::CancelledSignersProposal
+    );
+}
+
+#[test]
+fn pause_scope() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let scope = PauseScope::SourceChain(String::from_str(&env, DESTINATION_CHAIN));
+
+    assert!(!client.is_scope_paused(&scope));
+
+    assert_auth!(client.owner(), client.pause_scope(&scope));
+    goldie::assert!(events::fmt_last_emitted_event::<ScopePausedEvent>(&env));
+
+    assert!(client.is_scope_paused(&scope));
+    assert!(!client.is_scope_paused(&PauseScope::Inbound));
+    assert!(!client.paused());
+}
+
+#[test]
+fn unpause_scope() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let scope = PauseScope::Execution;
+
+    client.mock_all_auths().pause_scope(&scope);
+
+    assert_auth!(client.owner(), client.unpause_scope(&scope));
+    goldie::assert!(events::fmt_last_emitted_event::<ScopeUnpausedEvent>(&env));
+
+    assert!(!client.is_scope_paused(&scope));
+}
+
+#[test]
+fn pause_scope_unauthorized() {
+    let (_env, _signers, client) = setup_env(1, randint(1, 10));
+    let scope = PauseScope::Outbound;
+
+    assert_auth_err!(client.operator(), client.pause_scope(&scope));
+    assert_auth_err!(client.operator(), client.unpause_scope(&scope));
+}
+
+#[test]
+fn call_contract_fails_when_outbound_paused() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let user = Address::generate(&env);
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let destination_address = String::from_str(&env, DESTINATION_ADDRESS);
+    let payload = bytes!(&env, 0x1234);
+
+    client.mock_all_auths().pause_scope(&PauseScope::Outbound);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_call_contract(
+            &user,
+            &destination_chain,
+            &destination_address,
+            &payload
+        ),
+        ContractError::OutboundPaused
+    );
+}
+
+#[test]
+fn call_contract_fails_when_destination_chain_paused() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let user = Address::generate(&env);
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let other_destination_chain = String::from_str(&env, "avalanche");
+    let destination_address = String::from_str(&env, DESTINATION_ADDRESS);
+    let payload = bytes!(&env, 0x1234);
+
+    client
+        .mock_all_auths()
+        .pause_scope(&PauseScope::DestinationChain(destination_chain.clone()));
+
+    assert_contract_err!(
+        client.mock_all_auths().try_call_contract(
+            &user,
+            &destination_chain,
+            &destination_address,
+            &payload
+        ),
+        ContractError::DestinationChainPaused
+    );
+
+    client.mock_all_auths().call_contract(
+        &user,
+        &other_destination_chain,
+        &destination_address,
+        &payload,
+    );
+}
+
+#[test]
+fn set_destination_chain() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+
+    assert_auth!(
+        client.operator(),
+        client.set_destination_chain(&destination_chain, &MAX_PAYLOAD_LENGTH)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<DestinationChainSetEvent>(
+        &env
+    ));
+
+    assert!(client.is_destination_chain(&destination_chain));
+    assert_eq!(
+        client.max_payload_length(&destination_chain),
+        MAX_PAYLOAD_LENGTH
+    );
+}
+
+#[test]
+fn set_destination_chain_updates_max_payload_length() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let max_payload_length: u32 = 64;
+
+    client
+        .mock_all_auths()
+        .set_destination_chain(&destination_chain, &MAX_PAYLOAD_LENGTH);
+    client
+        .mock_all_auths()
+        .set_destination_chain(&destination_chain, &max_payload_length);
+
+    assert!(client.is_destination_chain(&destination_chain));
+    assert_eq!(
+        client.max_payload_length(&destination_chain),
+        max_payload_length
+    );
+}
+
+#[test]
+fn remove_destination_chain() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let other_destination_chain = String::from_str(&env, "avalanche");
+
+    client
+        .mock_all_auths()
+        .set_destination_chain(&destination_chain, &MAX_PAYLOAD_LENGTH);
+    client
+        .mock_all_auths()
+        .set_destination_chain(&other_destination_chain, &MAX_PAYLOAD_LENGTH);
+
+    assert_auth!(
+        client.operator(),
+        client.remove_destination_chain(&destination_chain)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<DestinationChainRemovedEvent>(&env));
+
+    assert!(!client.is_destination_chain(&destination_chain));
+    assert!(client.is_destination_chain(&other_destination_chain));
+    assert_contract_err!(
+        client.try_max_payload_length(&destination_chain),
+        ContractError::DestinationChainNotAllowed
+    );
+}
+
+#[test]
+fn remove_destination_chain_fails_if_not_allowed() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_remove_destination_chain(&destination_chain),
+        ContractError::DestinationChainNotAllowed
+    );
+}
+
+#[test]
+fn destination_chain_config_fails_on_invalid_auth() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+
+    assert_auth_err!(
+        client.owner(),
+        client.set_destination_chain(&destination_chain, &MAX_PAYLOAD_LENGTH)
+    );
+    assert_auth_err!(
+        client.owner(),
+        client.remove_destination_chain(&destination_chain)
+    );
+    assert_auth_err!(client.owner(), client.enable_chain_allowlist());
+    assert_auth_err!(client.owner(), client.disable_chain_allowlist());
+}
+
+#[test]
+fn enable_chain_allowlist() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+
+    assert!(!client.is_chain_allowlist_enabled());
+
+    assert_auth!(client.operator(), client.enable_chain_allowlist());
+    goldie::assert!(events::fmt_last_emitted_event::<ChainAllowlistEnabledEvent>(&env));
+
+    assert!(client.is_chain_allowlist_enabled());
+}
+
+#[test]
+fn disable_chain_allowlist() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let user = Address::generate(&env);
+    let destination_chain = String::from_str(&env, "unknown");
+    let destination_address = String::from_str(&env, DESTINATION_ADDRESS);
+    let payload = bytes!(&env, 0x1234);
+
+    client.mock_all_auths().enable_chain_allowlist();
+
+    assert_auth!(client.operator(), client.disable_chain_allowlist());
+    goldie::assert!(events::fmt_last_emitted_event::<ChainAllowlistDisabledEvent>(&env));
+
+    assert!(!client.is_chain_allowlist_enabled());
+    client.mock_all_auths().call_contract(
+        &user,
+        &destination_chain,
+        &destination_address,
+        &payload,
+    );
+}
+
+#[test]
+fn call_contract_fails_if_destination_chain_not_allowed() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let user = Address::generate(&env);
+    let destination_chain = String::from_str(&env, "unknown");
+    let destination_address = String::from_str(&env, DESTINATION_ADDRESS);
+    let payload = bytes!(&env, 0x1234);
+
+    client.mock_all_auths().enable_chain_allowlist();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_call_contract(
+            &user,
+            &destination_chain,
+            &destination_address,
+            &payload
+        ),
+        ContractError::DestinationChainNotAllowed
+    );
+}
+
+#[test]
+fn call_contract_fails_if_payload_too_large() {
+    let (env, _signers, client) = setup_env(1, randint(1, 10));
+    let user = Address::generate(&env);
+    let destination_chain = String::from_str(&env, DESTINATION_CHAIN);
+    let destination_address = String::from_str(&env, DESTINATION_ADDRESS);
+    let max_payload_length: u32 = 2;
+
+    client
+        .mock_all_auths()
+        .set_destination_chain(&destination_chain, &max_payload_length);
+
+    client.mock_all_auths().call_contract(
+        &user,
+        &destination_chain,
+        &destination_address,
+        &bytes!(&env, 0x1234),
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_call_contract(
+            &user,
+            &destination_chain,
+            &destination_address,
+            &bytes!(&env, 0x123456)
+        ),
+        ContractError::PayloadTooLarge
+    );
+}
+
+#[test]
+fn approve_messages_fails_when_inbound_paused() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+
+    client.mock_all_auths().pause_scope(&PauseScope::Inbound);
+
+    let messages = vec![&env, message];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers.clone());
+    assert_contract_err!(
+        client.try_approve_messages(&messages, &proof),
+        ContractError::InboundPaused
+    );
+
+    let root = BytesN::random(&env);
+    let data_hash = get_approve_messages_root_hash(&env, root.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+    assert_contract_err!(
+        client.try_approve_messages_root(&root, &proof),
+        ContractError::InboundPaused
+    );
+}
+
+#[test]
+fn approve_messages_skips_paused_source_chain() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (paused_message, _) = generate_test_message(&env);
+    let (message, _) = generate_test_message(&env);
+
+    client
+        .mock_all_auths()
+        .pause_scope(&PauseScope::SourceChain(
+            paused_message.source_chain.clone(),
+        ));
+
+    let messages = vec![&env, paused_message.clone(), message];
+    let data_hash = get_approve_hash(&env, messages.clone());
+    let proof = generate_proof(&env, data_hash, signers);
+
+    assert_eq!(
+        client.approve_messages(&messages, &proof),
+        vec![
+            &env,
+            MessageApprovalStatus::SourceChainPaused,
+            MessageApprovalStatus::NewlyApproved
+        ]
     );
+    assert!(!client.is_message_approved(
+        &paused_message.source_chain,
+        &paused_message.message_id,
+        &paused_message.source_address,
+        &paused_message.contract_address,
+        &paused_message.payload_hash,
+    ));
+}
+
+#[test]
+fn validate_message_fails_when_execution_paused() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+    let (message, _) = generate_test_message(&env);
+    approve_gateway_messages(&env, &client, signers, vec![&env, message.clone()]);
+
+    for scope in [
+        PauseScope::Execution,
+        PauseScope::SourceChain(message.source_chain.clone()),
+    ] {
+        client.mock_all_auths().pause_scope(&scope);
+
+        assert!(!client.mock_all_auths().validate_message(
+            &message.contract_address,
+            &message.source_chain,
+            &message.message_id,
+            &message.source_address,
+            &message.payload_hash,
+        ));
+
+        client.mock_all_auths().unpause_scope(&scope);
+    }
+
+    assert!(client.mock_all_auths().validate_message(
+        &message.contract_address,
+        &message.source_chain,
+        &message.message_id,
+        &message.source_address,
+        &message.payload_hash,
+    ));
 }
 
 #[test]
@@ -0,0 +1,78 @@
+use soroban_sdk::testutils::BytesN as _;
+use soroban_sdk::{vec, BytesN, Vec};
+use stellar_axelar_std::assert_err;
+use stellar_axelar_std::interfaces::CustomMigratableInterface;
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::testutils::{generate_proof, generate_signers_set, randint};
+use crate::types::WeightedSigners;
+use crate::{storage, AxelarGateway};
+
+#[test]
+fn migrate_backfills_signers_of_retained_epochs() {
+    let (env, signers, client) = setup_env(1, randint(1, 10));
+
+    let new_signers = generate_signers_set(&env, 5, signers.domain_separator.clone());
+    let data_hash = new_signers.signers.signers_rotation_hash(&env);
+    let proof = generate_proof(&env, data_hash, signers.clone());
+    client.rotate_signers(&new_signers.signers, &proof, &false);
+
+    // Signers rotated in before full signer sets were stored are missing
+    env.as_contract(&client.address, || {
+        storage::remove_signers_by_epoch(&env, 1);
+        storage::remove_signers_by_epoch(&env, 2);
+    });
+
+    let migration_data = vec![&env, signers.signers.clone(), new_signers.signers.clone()];
+
+    assert_eq!(
+        env.as_contract(&client.address, || {
+            <AxelarGateway as CustomMigratableInterface>::__migrate(&env, migration_data)
+        }),
+        Ok(())
+    );
+
+    assert_eq!(client.signers_by_epoch(&1), signers.signers);
+    assert_eq!(client.signers_by_epoch(&2), new_signers.signers);
+
+    let msg_hash: BytesN<32> = BytesN::random(&env);
+    let proof = generate_proof(&env, msg_hash.clone(), signers);
+    assert!(!client.validate_proof(&msg_hash, &proof));
+}
+
+#[test]
+fn migrate_fails_with_unknown_signers() {
+    let (env, signers, client) = setup_env(0, randint(1, 10));
+
+    let unknown_signers = generate_signers_set(&env, 5, signers.domain_separator);
+
+    assert_err!(
+        env.as_contract(&client.address, || {
+            <AxelarGateway as CustomMigratableInterface>::__migrate(
+                &env,
+                vec![&env, unknown_signers.signers],
+            )
+        }),
+        ContractError::InvalidSignersHash
+    );
+}
+
+#[test]
+fn migrate_fails_with_missing_retained_signers() {
+    let (env, _, client) = setup_env(0, randint(1, 10));
+
+    env.as_contract(&client.address, || {
+        storage::remove_signers_by_epoch(&env, 1);
+    });
+
+    assert_err!(
+        env.as_contract(&client.address, || {
+            <AxelarGateway as CustomMigratableInterface>::__migrate(
+                &env,
+                Vec::<WeightedSigners>::new(&env),
+            )
+        }),
+        ContractError::InvalidEpoch
+    );
+}
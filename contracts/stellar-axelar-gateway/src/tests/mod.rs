@@ -1,3 +1,5 @@
 mod auth;
+mod express_executable;
 mod gateway;
+mod migrate;
 mod utils;
@@ -1,21 +1,121 @@
 extern crate std;
 
-use ed25519_dalek::{Signature, Signer, SigningKey};
+use ed25519_dalek::{Signature, Signer};
 use rand::distributions::{Alphanumeric, DistString};
 use rand::Rng;
-use soroban_sdk::testutils::{Address as _, BytesN as _};
+use soroban_sdk::crypto::bls12_381::{Fr, G1Affine, G2Affine};
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::testutils::{Address as _, BytesN as _, Events};
 use soroban_sdk::xdr::ToXdr;
-use soroban_sdk::{vec, Address, Bytes, BytesN, Env, String, Vec};
+use soroban_sdk::{bytesn, vec, Address, Bytes, BytesN, Env, FromVal, Map, String, Symbol, Vec};
+use stellar_axelar_std::events::Event;
 use stellar_axelar_std::traits::IntoVec;
 
+use crate::event::ContractCalledEvent;
 use crate::types::{
-    CommandType, Message, Proof, ProofSignature, ProofSigner, WeightedSigner, WeightedSigners,
+    AggregateProof, CommandType, CompactProof, Message, MessageInclusionProof, Proof,
+    ProofSignature, ProofSigner, SignaturesProof, SignerPublicKey, WeightedSigner, WeightedSigners,
 };
 use crate::{AxelarGateway, AxelarGatewayClient};
 
+const BLS_SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+const BLS_POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// The standard BLS12-381 G1 generator, in uncompressed form.
+pub fn bls_g1_generator(env: &Env) -> G1Affine {
+    G1Affine::from_bytes(bytesn!(
+        env,
+        0x17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1
+    ))
+}
+
+#[derive(Clone, Debug)]
+pub enum TestSigningKey {
+    Ed25519(ed25519_dalek::SigningKey),
+    Secp256k1(k256::ecdsa::SigningKey),
+    Bls12381([u8; 32]),
+}
+
+impl TestSigningKey {
+    pub fn public_key(&self, env: &Env) -> SignerPublicKey {
+        match self {
+            Self::Ed25519(signing_key) => SignerPublicKey::Ed25519(BytesN::from_array(
+                env,
+                &signing_key.verifying_key().to_bytes(),
+            )),
+            Self::Secp256k1(signing_key) => {
+                let public_key: [u8; 33] = signing_key
+                    .verifying_key()
+                    .to_encoded_point(true)
+                    .as_bytes()
+                    .try_into()
+                    .expect("invalid compressed public key");
+                SignerPublicKey::Secp256k1(BytesN::from_array(env, &public_key))
+            }
+            Self::Bls12381(secret_key) => {
+                let public_key = env.crypto().bls12_381().g1_mul(
+                    &bls_g1_generator(env),
+                    &Fr::from_bytes(BytesN::from_array(env, secret_key)),
+                );
+
+                SignerPublicKey::Bls12381(public_key.to_bytes())
+            }
+        }
+    }
+
+    pub fn sign(&self, env: &Env, msg_hash: &[u8; 32]) -> ProofSignature {
+        match self {
+            Self::Ed25519(signing_key) => {
+                let signature: Signature = signing_key.sign(msg_hash);
+                ProofSignature::Signed(BytesN::from_array(env, &signature.to_bytes()))
+            }
+            Self::Secp256k1(signing_key) => {
+                let (signature, recovery_id) = signing_key
+                    .sign_prehash_recoverable(msg_hash)
+                    .expect("failed to sign");
+
+                let mut recoverable_signature = [0u8; 65];
+                recoverable_signature[..64].copy_from_slice(&signature.to_bytes());
+                recoverable_signature[64] = recovery_id.to_byte();
+
+                ProofSignature::SignedSecp256k1(BytesN::from_array(env, &recoverable_signature))
+            }
+            Self::Bls12381(_) => panic!("BLS12-381 signers can only sign aggregate proofs"),
+        }
+    }
+
+    /// Sign the BLS12-381 public key to prove possession of the secret key.
+    pub fn bls_proof_of_possession(&self, env: &Env) -> BytesN<192> {
+        let SignerPublicKey::Bls12381(public_key) = self.public_key(env) else {
+            panic!("not a BLS12-381 signer");
+        };
+
+        self.sign_bls_with_dst(env, &public_key.into(), BLS_POP_DST)
+            .to_bytes()
+    }
+
+    pub fn sign_bls(&self, env: &Env, msg_hash: &Hash<32>) -> G2Affine {
+        self.sign_bls_with_dst(env, &msg_hash.to_bytes().into(), BLS_SIGNATURE_DST)
+    }
+
+    fn sign_bls_with_dst(&self, env: &Env, msg: &Bytes, dst: &[u8]) -> G2Affine {
+        let Self::Bls12381(secret_key) = self else {
+            panic!("not a BLS12-381 signer");
+        };
+
+        let bls = env.crypto().bls12_381();
+        let msg_point = bls.hash_to_g2(msg, &Bytes::from_slice(env, dst));
+
+        bls.g2_mul(
+            &msg_point,
+            &Fr::from_bytes(BytesN::from_array(env, secret_key)),
+        )
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TestSignerSet {
-    pub signer_keys: std::vec::Vec<SigningKey>,
+    pub signer_keys: std::vec::Vec<TestSigningKey>,
     pub signers: WeightedSigners,
     pub domain_separator: BytesN<32>,
 }
@@ -53,6 +153,59 @@ pub fn get_approve_hash(env: &Env, messages: Vec<Message>) -> BytesN<32> {
         .into()
 }
 
+pub fn get_revoke_hash(env: &Env, messages: Vec<Message>) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&(CommandType::RevokeMessages, messages).to_xdr(env))
+        .into()
+}
+
+pub fn get_approve_messages_root_hash(env: &Env, root: BytesN<32>) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&(CommandType::ApproveMessagesRoot, root).to_xdr(env))
+        .into()
+}
+
+/// Build a Merkle tree over `messages`, returning the root and an inclusion proof for each message.
+pub fn generate_messages_merkle_tree(
+    env: &Env,
+    messages: &[Message],
+) -> (BytesN<32>, std::vec::Vec<MessageInclusionProof>) {
+    let mut level: std::vec::Vec<BytesN<32>> =
+        messages.iter().map(|message| message.hash(env)).collect();
+    let mut positions: std::vec::Vec<usize> = (0..messages.len()).collect();
+    let mut siblings: std::vec::Vec<Vec<BytesN<32>>> =
+        messages.iter().map(|_| Vec::new(env)).collect();
+
+    while level.len() > 1 {
+        for (position, proof_siblings) in positions.iter_mut().zip(siblings.iter_mut()) {
+            if let Some(sibling) = level.get(*position ^ 1) {
+                proof_siblings.push_back(sibling.clone());
+            }
+            *position /= 2;
+        }
+
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => MessageInclusionProof::hash_pair(env, left.clone(), right.clone()),
+                [node] => node.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    let root = level[0].clone();
+    let proofs = siblings
+        .into_iter()
+        .map(|siblings| MessageInclusionProof {
+            root: root.clone(),
+            siblings,
+        })
+        .collect();
+
+    (root, proofs)
+}
+
 pub fn generate_test_message(env: &Env) -> (Message, Bytes) {
     generate_test_message_with_rng(env, rand::thread_rng())
 }
@@ -97,27 +250,117 @@ pub fn generate_signers_set_with_rng(
     domain_separator: BytesN<32>,
     mut rng: impl Rng + rand::CryptoRng,
 ) -> TestSignerSet {
-    let mut signer_keypair: std::vec::Vec<_> = (0..num_signers)
+    let signer_keypair: std::vec::Vec<_> = (0..num_signers)
+        .map(|_| {
+            let signing_key =
+                TestSigningKey::Ed25519(ed25519_dalek::SigningKey::generate(&mut rng));
+            let weight = rng.gen_range(1..10) as u128;
+            (signing_key, weight)
+        })
+        .collect();
+
+    signers_set_from_keys(env, signer_keypair, domain_separator, rng)
+}
+
+/// Generate a signer set of secp256k1 (ECDSA) signers.
+pub fn generate_secp256k1_signers_set(
+    env: &Env,
+    num_signers: u64,
+    domain_separator: BytesN<32>,
+) -> TestSignerSet {
+    let mut rng = rand::thread_rng();
+
+    let signer_keypair: std::vec::Vec<_> = (0..num_signers)
+        .map(|_| {
+            let signing_key = TestSigningKey::Secp256k1(k256::ecdsa::SigningKey::random(&mut rng));
+            let weight = rng.gen_range(1..10) as u128;
+            (signing_key, weight)
+        })
+        .collect();
+
+    signers_set_from_keys(env, signer_keypair, domain_separator, rng)
+}
+
+/// Generate a signer set of BLS12-381 signers, which can only produce aggregate proofs.
+pub fn generate_bls_signers_set(
+    env: &Env,
+    num_signers: u64,
+    domain_separator: BytesN<32>,
+) -> TestSignerSet {
+    let mut rng = rand::thread_rng();
+
+    // Key generation happens off-chain, so it shouldn't count against the budget of subsequent contract calls
+    env.cost_estimate().budget().reset_unlimited();
+
+    let signer_keypair: std::vec::Vec<_> = (0..num_signers)
         .map(|_| {
-            let signing_key = SigningKey::generate(&mut rng);
+            let mut secret_key: [u8; 32] = rng.gen();
+            // Keep the secret key below the scalar field order
+            secret_key[0] &= 0x3f;
+            let weight = rng.gen_range(1..10) as u128;
+            (TestSigningKey::Bls12381(secret_key), weight)
+        })
+        .collect();
+
+    let signer_set = signers_set_from_keys(env, signer_keypair, domain_separator, rng);
+
+    env.cost_estimate().budget().reset_default();
+
+    signer_set
+}
+
+/// Register the public keys of the BLS12-381 signers of the signer set with the gateway.
+pub fn register_bls_signers(
+    env: &Env,
+    gateway_client: &AxelarGatewayClient,
+    signer_set: &TestSignerSet,
+) {
+    for signing_key in &signer_set.signer_keys {
+        if let SignerPublicKey::Bls12381(public_key) = signing_key.public_key(env) {
+            gateway_client
+                .register_bls_public_key(&public_key, &signing_key.bls_proof_of_possession(env));
+        }
+    }
+}
+
+/// Generate a signer set alternating between Ed25519 and secp256k1 signers.
+pub fn generate_mixed_signers_set(
+    env: &Env,
+    num_signers: u64,
+    domain_separator: BytesN<32>,
+) -> TestSignerSet {
+    let mut rng = rand::thread_rng();
+
+    let signer_keypair: std::vec::Vec<_> = (0..num_signers)
+        .map(|i| {
+            let signing_key = if i % 2 == 0 {
+                TestSigningKey::Ed25519(ed25519_dalek::SigningKey::generate(&mut rng))
+            } else {
+                TestSigningKey::Secp256k1(k256::ecdsa::SigningKey::random(&mut rng))
+            };
             let weight = rng.gen_range(1..10) as u128;
             (signing_key, weight)
         })
         .collect();
 
+    signers_set_from_keys(env, signer_keypair, domain_separator, rng)
+}
+
+fn signers_set_from_keys(
+    env: &Env,
+    mut signer_keypair: std::vec::Vec<(TestSigningKey, u128)>,
+    domain_separator: BytesN<32>,
+    mut rng: impl Rng + rand::CryptoRng,
+) -> TestSignerSet {
     // Sort signers by public key
-    signer_keypair.sort_by(|a, b| {
-        a.0.verifying_key()
-            .to_bytes()
-            .cmp(&b.0.verifying_key().to_bytes())
-    });
+    signer_keypair.sort_by_key(|(signing_key, _)| signing_key.public_key(env));
 
     let total_weight = signer_keypair.iter().map(|(_, w)| w).sum::<u128>();
 
     let signer_vec: std::vec::Vec<WeightedSigner> = signer_keypair
         .iter()
         .map(|(signing_key, w)| WeightedSigner {
-            signer: BytesN::<32>::from_array(env, &signing_key.verifying_key().to_bytes()),
+            signer: signing_key.public_key(env),
             weight: *w,
         })
         .collect();
@@ -140,16 +383,26 @@ pub fn generate_signers_set_with_rng(
     }
 }
 
-pub fn generate_proof(env: &Env, data_hash: BytesN<32>, signer_set: TestSignerSet) -> Proof {
-    let signers_hash = env
-        .crypto()
-        .keccak256(&signer_set.signers.clone().to_xdr(env));
+fn message_hash_to_sign(env: &Env, data_hash: BytesN<32>, signer_set: &TestSignerSet) -> Hash<32> {
+    let signers_hash = signer_set.signers.hash(env);
 
-    let mut msg: Bytes = signer_set.domain_separator.into();
+    let mut msg: Bytes = signer_set.domain_separator.clone().into();
     msg.extend_from_array(&signers_hash.to_array());
     msg.extend_from_array(&data_hash.to_array());
 
-    let msg_hash = env.crypto().keccak256(&msg);
+    env.crypto().keccak256(&msg)
+}
+
+pub fn generate_proof(env: &Env, data_hash: BytesN<32>, signer_set: TestSignerSet) -> Proof {
+    generate_signatures_proof(env, data_hash, signer_set).into()
+}
+
+pub fn generate_signatures_proof(
+    env: &Env,
+    data_hash: BytesN<32>,
+    signer_set: TestSignerSet,
+) -> SignaturesProof {
+    let msg_hash = message_hash_to_sign(env, data_hash, &signer_set);
     let threshold = signer_set.signers.threshold as usize;
 
     let proof_signers: std::vec::Vec<_> = signer_set
@@ -165,24 +418,86 @@ pub fn generate_proof(env: &Env, data_hash: BytesN<32>, signer_set: TestSignerSe
                 };
             }
 
-            let signature: Signature = signing_key.sign(&msg_hash.to_array());
             ProofSigner {
                 signer: weighted_signer,
-                signature: ProofSignature::Signed(BytesN::<64>::from_array(
-                    env,
-                    &signature.to_bytes(),
-                )),
+                signature: signing_key.sign(env, &msg_hash.to_array()),
             }
         })
         .collect();
 
-    Proof {
+    SignaturesProof {
         signers: proof_signers.into_vec(env),
         threshold: signer_set.signers.threshold,
         nonce: signer_set.signers.nonce,
     }
 }
 
+/// Generate a compact proof, referencing the signer set by its hash.
+pub fn generate_compact_proof(
+    env: &Env,
+    data_hash: BytesN<32>,
+    signer_set: TestSignerSet,
+) -> CompactProof {
+    let signers_hash = signer_set.signers.hash(env);
+    let proof = generate_signatures_proof(env, data_hash, signer_set);
+
+    let mut signatures = Map::new(env);
+    for (i, ProofSigner { signature, .. }) in proof.signers.into_iter().enumerate() {
+        if signature != ProofSignature::Unsigned {
+            signatures.set(i as u32, signature);
+        }
+    }
+
+    CompactProof {
+        signers_hash,
+        signatures,
+    }
+}
+
+/// Generate an aggregate proof from a BLS12-381 signer set, aggregating signatures until the threshold is met.
+pub fn generate_aggregate_proof(
+    env: &Env,
+    data_hash: BytesN<32>,
+    signer_set: TestSignerSet,
+) -> AggregateProof {
+    // Signing happens off-chain, so it shouldn't count against the budget of subsequent contract calls
+    env.cost_estimate().budget().reset_unlimited();
+
+    let msg_hash = message_hash_to_sign(env, data_hash, &signer_set);
+    let bls = env.crypto().bls12_381();
+
+    let mut signer_bitmap = std::vec![0u8; signer_set.signer_keys.len().div_ceil(8)];
+    let mut signature: Option<G2Affine> = None;
+    let mut total_weight = 0u128;
+
+    for (i, (signing_key, weighted_signer)) in signer_set
+        .signer_keys
+        .iter()
+        .zip(signer_set.signers.signers.iter())
+        .enumerate()
+    {
+        if total_weight >= signer_set.signers.threshold {
+            break;
+        }
+
+        let partial_signature = signing_key.sign_bls(env, &msg_hash);
+        signature = Some(match signature {
+            Some(signature) => bls.g2_add(&signature, &partial_signature),
+            None => partial_signature,
+        });
+        signer_bitmap[i / 8] |= 1 << (i % 8);
+        total_weight += weighted_signer.weight;
+    }
+
+    env.cost_estimate().budget().reset_default();
+
+    AggregateProof {
+        signers: signer_set.signers,
+        signer_bitmap: Bytes::from_slice(env, &signer_bitmap),
+        signature: signature.expect("empty signer set").to_bytes(),
+    }
+}
+
 pub fn approve_gateway_messages(
     env: &Env,
     gateway_client: &AxelarGatewayClient,
@@ -193,3 +508,21 @@ pub fn approve_gateway_messages(
     let proof = generate_proof(env, data_hash, signers);
     gateway_client.approve_messages(&messages, &proof);
 }
+
+/// Returns the message id of the last contract call sent through the gateway, as emitted in its [`ContractCalledEvent`].
+pub fn last_contract_call_message_id(env: &Env, gateway_client: &AxelarGatewayClient) -> String {
+    let contract_called = Symbol::new(env, "contract_called");
+
+    env.events()
+        .all()
+        .iter()
+        .rev()
+        .find(|(contract, topics, _)| {
+            *contract == gateway_client.address
+                && topics
+                    .first()
+                    .is_some_and(|topic| Symbol::from_val(env, &topic) == contract_called)
+        })
+        .map(|(_, topics, data)| ContractCalledEvent::from_event(env, topics, data).message_id)
+        .expect("no contract call emitted")
+}
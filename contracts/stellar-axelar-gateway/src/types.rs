@@ -1,10 +1,22 @@
 use soroban_sdk::xdr::ToXdr;
-use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, IntoVal, Map, String, Val, Vec};
+
+/// `SignerPublicKey` represents the public key of a signer, tagged with its signature scheme.
+///
+/// Keys are ordered by scheme first and then by the key bytes, which defines the
+/// required ordering of signers within a `WeightedSigners` set.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SignerPublicKey {
+    Ed25519(BytesN<32>),
+    Secp256k1(BytesN<33>), // SEC-1 compressed ECDSA public key
+    Bls12381(BytesN<96>),  // Uncompressed G1 point, only usable in aggregate proofs once registered
+}
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct WeightedSigner {
-    pub signer: BytesN<32>, // Ed25519 public key
+    pub signer: SignerPublicKey,
     pub weight: u128,
 }
 
@@ -16,13 +28,34 @@ pub struct WeightedSigners {
     pub nonce: BytesN<32>,
 }
 
+/// The encoding of a weighted signer from before signers were tagged with their signature scheme.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LegacyWeightedSigner {
+    signer: BytesN<32>, // Ed25519 public key
+    weight: u128,
+}
+
+/// The encoding of weighted signers from before signers were tagged with their signature scheme.
+///
+/// Signer sets that only consist of Ed25519 signers are hashed in this encoding, so that the hashes of
+/// signer sets rotated in before other signature schemes were supported remain unchanged.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LegacyWeightedSigners {
+    signers: Vec<LegacyWeightedSigner>,
+    threshold: u128,
+    nonce: BytesN<32>,
+}
+
 /// `ProofSignature` represents an optional signature from a signer.
 /// Since Soroban doesn't support use of `Option` in it's contract interfaces,
 /// we use this enum instead.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ProofSignature {
-    Signed(BytesN<64>), // Ed25519 signature
+    Signed(BytesN<64>),          // Ed25519 signature
+    SignedSecp256k1(BytesN<65>), // Recoverable ECDSA signature (r || s || recovery id)
     Unsigned,
 }
 
@@ -37,22 +70,108 @@ pub struct ProofSigner {
     pub signature: ProofSignature,
 }
 
-/// `Proof` represents a proof that a set of signers have signed a message.
+/// `SignaturesProof` represents a proof that a set of signers have signed a message.
+///
 /// All weighted signers are included in the along with a signature, if they have signed the message,
 /// until threshold is met.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Proof {
+pub struct SignaturesProof {
     pub signers: Vec<ProofSigner>,
     pub threshold: u128,
     pub nonce: BytesN<32>,
 }
 
+/// `AggregateProof` represents a proof that a subset of BLS12-381 signers have signed a message.
+///
+/// Instead of one signature per signer, a single signature aggregated over all participating signers
+/// is attached. Participation is tracked by `signer_bitmap`, where bit `i % 8` of byte `i / 8` is set if
+/// the signer at index `i` of `signers` contributed to the aggregated signature. Bits beyond the last signer must be unset.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateProof {
+    pub signers: WeightedSigners,
+    pub signer_bitmap: Bytes,
+    pub signature: BytesN<192>, // Aggregated G2 signature
+}
+
+/// `CompactProof` represents a proof that a set of signers have signed a message,
+/// without repeating the signer set itself.
+///
+/// The signer set is referenced by its hash and must be stored by the gateway, i.e. it must have been
+/// rotated in. Signatures are keyed by the index of the signer within the referenced signer set.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompactProof {
+    pub signers_hash: BytesN<32>,
+    pub signatures: Map<u32, ProofSignature>,
+}
+
+/// `Proof` represents a proof that a set of signers have signed a message, in one of the supported formats.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Proof {
+    Signatures(SignaturesProof),
+    Aggregate(AggregateProof),
+    Compact(CompactProof),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CommandType {
     ApproveMessages,
     RotateSigners,
+    ProposeSigners,
+    ApproveMessagesRoot,
+    RevokeMessages,
+}
+
+/// A signer rotation that has been proposed by the signers of `epoch`, and can be executed once `activation_timestamp` is reached.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingSigners {
+    pub signers: WeightedSigners,
+    pub activation_timestamp: u64,
+    pub epoch: u64,
+}
+
+/// The outcome of approving a message in a batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MessageApprovalStatus {
+    NewlyApproved,
+    AlreadyApproved,
+    AlreadyExecuted,
+    SourceChainPaused,
+    AlreadyRevoked,
+}
+
+/// The status of an incoming message on the gateway.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MessageStatus {
+    /// The message was never approved, or its approval has expired.
+    NotApproved,
+    /// The message is approved and awaiting execution. Holds the hash of the approved message.
+    Approved(BytesN<32>),
+    Executed,
+    Revoked,
+}
+
+/// A part of the gateway that can be paused independently of the contract wide pause.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    /// Approvals of messages from all source chains.
+    Inbound,
+    /// Contract calls to all destination chains.
+    Outbound,
+    /// Validation of approved messages for execution.
+    Execution,
+    /// Approvals and execution of messages from the given source chain.
+    SourceChain(String),
+    /// Contract calls to the given destination chain.
+    DestinationChain(String),
 }
 
 #[contracttype]
@@ -65,19 +184,163 @@ pub struct Message {
     pub payload_hash: BytesN<32>,
 }
 
-impl WeightedSigners {
+/// `MessageInclusionProof` proves that a message is a leaf of an approved Merkle `root` over a batch of messages.
+///
+/// Nodes are hashed as sorted pairs, so the position of the leaf isn't required to recompute the root.
+/// A node without a sibling on its level is carried up unchanged, and has no entry in `siblings`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MessageInclusionProof {
+    pub root: BytesN<32>,
+    pub siblings: Vec<BytesN<32>>,
+}
+
+impl Message {
+    /// Get the hash of the message, used both for individual approvals and as a Merkle leaf.
     pub fn hash(&self, env: &Env) -> BytesN<32> {
         env.crypto().keccak256(&self.clone().to_xdr(env)).into()
     }
+}
+
+impl MessageInclusionProof {
+    /// Returns true if `message` is included in the `root` of the proof.
+    pub fn includes(&self, env: &Env, message: &Message) -> bool {
+        let computed_root = self
+            .siblings
+            .iter()
+            .fold(message.hash(env), |node, sibling| {
+                Self::hash_pair(env, node, sibling)
+            });
+
+        computed_root == self.root
+    }
+
+    pub fn hash_pair(env: &Env, a: BytesN<32>, b: BytesN<32>) -> BytesN<32> {
+        let (left, right) = if a < b { (a, b) } else { (b, a) };
+
+        let mut pair = Bytes::from(left);
+        pair.append(&right.into());
+
+        env.crypto().keccak256(&pair).into()
+    }
+}
+
+impl WeightedSigners {
+    pub fn hash(&self, env: &Env) -> BytesN<32> {
+        env.crypto()
+            .keccak256(&self.hash_encoding(env).to_xdr(env))
+            .into()
+    }
 
     pub fn signers_rotation_hash(&self, env: &Env) -> BytesN<32> {
         env.crypto()
-            .keccak256(&(CommandType::RotateSigners, self.clone()).to_xdr(env))
+            .keccak256(&(CommandType::RotateSigners, self.hash_encoding(env)).to_xdr(env))
             .into()
     }
+
+    pub fn signers_proposal_hash(&self, env: &Env, activation_timestamp: u64) -> BytesN<32> {
+        env.crypto()
+            .keccak256(
+                &(
+                    CommandType::ProposeSigners,
+                    self.hash_encoding(env),
+                    activation_timestamp,
+                )
+                    .to_xdr(env),
+            )
+            .into()
+    }
+
+    /// Get the encoding of the signer set that its hashes are computed over.
+    ///
+    /// Signer sets that only consist of Ed25519 signers use the [`LegacyWeightedSigners`] encoding.
+    fn hash_encoding(&self, env: &Env) -> Val {
+        let mut legacy_signers = Vec::new(env);
+
+        for WeightedSigner { signer, weight } in self.signers.iter() {
+            let SignerPublicKey::Ed25519(signer) = signer else {
+                return self.clone().into_val(env);
+            };
+
+            legacy_signers.push_back(LegacyWeightedSigner { signer, weight });
+        }
+
+        LegacyWeightedSigners {
+            signers: legacy_signers,
+            threshold: self.threshold,
+            nonce: self.nonce.clone(),
+        }
+        .into_val(env)
+    }
 }
 
 impl Proof {
+    /// Get the hash of the weighted signers the proof was created by.
+    pub fn signers_hash(&self, env: &Env) -> BytesN<32> {
+        match self {
+            Self::Signatures(proof) => proof.weighted_signers().hash(env),
+            Self::Aggregate(proof) => proof.signers.hash(env),
+            Self::Compact(proof) => proof.signers_hash.clone(),
+        }
+    }
+}
+
+impl From<SignaturesProof> for Proof {
+    fn from(proof: SignaturesProof) -> Self {
+        Self::Signatures(proof)
+    }
+}
+
+impl From<AggregateProof> for Proof {
+    fn from(proof: AggregateProof) -> Self {
+        Self::Aggregate(proof)
+    }
+}
+
+impl From<CompactProof> for Proof {
+    fn from(proof: CompactProof) -> Self {
+        Self::Compact(proof)
+    }
+}
+
+impl SignaturesProof {
+    /// Expand a compact proof into a full proof, given the weighted signers it references.
+    ///
+    /// Returns `None` if the compact proof contains a signature for an index outside of the signer set.
+    pub fn from_compact(proof: CompactProof, weighted_signers: WeightedSigners) -> Option<Self> {
+        let WeightedSigners {
+            signers: weighted_signers,
+            threshold,
+            nonce,
+        } = weighted_signers;
+
+        if proof
+            .signatures
+            .keys()
+            .last()
+            .is_some_and(|index| index >= weighted_signers.len())
+        {
+            return None;
+        }
+
+        let mut signers = Vec::new(weighted_signers.env());
+
+        for (i, signer) in weighted_signers.into_iter().enumerate() {
+            let signature = proof
+                .signatures
+                .get(i as u32)
+                .unwrap_or(ProofSignature::Unsigned);
+
+            signers.push_back(ProofSigner { signer, signature });
+        }
+
+        Some(Self {
+            signers,
+            threshold,
+            nonce,
+        })
+    }
+
     /// Get the weighted signers from the proof.
     pub fn weighted_signers(&self) -> WeightedSigners {
         let mut signers = Vec::new(self.signers.env());
@@ -100,7 +363,7 @@ mod test {
     use soroban_sdk::xdr::ToXdr;
     use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
-    use crate::types::{CommandType, Message, WeightedSigner, WeightedSigners};
+    use crate::types::{CommandType, Message, SignerPublicKey, WeightedSigner, WeightedSigners};
 
     #[test]
     fn weighted_signers_hash() {
@@ -108,40 +371,90 @@ mod test {
 
         let signers = [
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: SignerPublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("0a245a2a2a5e8ec439d1377579a08fc78ea55647ba6fcb1f5d8a360218e8a985"),
-                ),
+                )),
                 weight: 3,
             },
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: SignerPublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("0b422cf449d900f6f8eb97f62e35811c62eb75feb84dfccef44a5c1c3dbac2ad"),
-                ),
+                )),
                 weight: 2,
             },
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: SignerPublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("18c34bf01a11b5ba21ea11b1678f3035ef753f0bdb1d5014ec21037e8f99e2a2"),
-                ),
+                )),
                 weight: 4,
             },
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: SignerPublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("f683ca8a6d7fe55f25599bb64b01edcc5eeb85fe5b63d3a4f0b3c32405005518"),
-                ),
+                )),
                 weight: 4,
             },
             WeightedSigner {
-                signer: BytesN::<32>::from_array(
+                signer: SignerPublicKey::Ed25519(BytesN::<32>::from_array(
                     &env,
                     &hex!("fbb4b870e800038f1379697fae3058938c59b696f38dd0fdf2659c0cf3a5b663"),
-                ),
+                )),
+                weight: 2,
+            },
+        ];
+
+        let weighted_signers = WeightedSigners {
+            signers: Vec::from_array(&env, signers),
+            threshold: 8u128,
+            nonce: BytesN::<32>::from_array(
+                &env,
+                &hex!("8784bf7be5a9baaeea47e12d9e8ad0dec29afcbc3617d97f771e3c24fa945dce"),
+            ),
+        };
+
+        let hash = weighted_signers.hash(&env).to_array();
+        let signers_rotation_hash = weighted_signers.signers_rotation_hash(&env).to_array();
+
+        goldie::assert_json!(vec![hex::encode(hash), hex::encode(signers_rotation_hash)]);
+    }
+
+    #[test]
+    fn weighted_signers_hash_with_mixed_schemes() {
+        let env = Env::default();
+
+        let signers = [
+            WeightedSigner {
+                signer: SignerPublicKey::Ed25519(BytesN::<32>::from_array(
+                    &env,
+                    &hex!("0a245a2a2a5e8ec439d1377579a08fc78ea55647ba6fcb1f5d8a360218e8a985"),
+                )),
+                weight: 3,
+            },
+            WeightedSigner {
+                signer: SignerPublicKey::Ed25519(BytesN::<32>::from_array(
+                    &env,
+                    &hex!("f683ca8a6d7fe55f25599bb64b01edcc5eeb85fe5b63d3a4f0b3c32405005518"),
+                )),
+                weight: 4,
+            },
+            WeightedSigner {
+                signer: SignerPublicKey::Secp256k1(BytesN::<33>::from_array(
+                    &env,
+                    &hex!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+                )),
                 weight: 2,
             },
+            WeightedSigner {
+                signer: SignerPublicKey::Secp256k1(BytesN::<33>::from_array(
+                    &env,
+                    &hex!("03c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5"),
+                )),
+                weight: 5,
+            },
         ];
 
         let weighted_signers = WeightedSigners {
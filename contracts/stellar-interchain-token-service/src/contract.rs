@@ -11,22 +11,24 @@ use stellar_axelar_std::address::AddressExt;
 use stellar_axelar_std::events::Event;
 use stellar_axelar_std::types::Token;
 use stellar_axelar_std::{
-    ensure, interfaces, only_operator, only_owner, when_not_paused, AxelarExecutable, Operatable,
-    Ownable, Pausable, Upgradable,
+    ensure, interfaces, only_owner, when_not_paused, AxelarExecutable, Operatable, Ownable,
+    Pausable, Upgradable,
 };
 use stellar_interchain_token::InterchainTokenClient;
 
 use crate::error::ContractError;
 use crate::event::{
     InterchainTokenDeploymentStartedEvent, InterchainTransferReceivedEvent,
-    InterchainTransferSentEvent, TrustedChainRemovedEvent, TrustedChainSetEvent,
+    InterchainTransferSentEvent, LinkTokenStartedEvent, TokenMetadataRegisteredEvent,
+    TrustedChainRemovedEvent, TrustedChainSetEvent,
 };
 use crate::flow_limit::FlowDirection;
 use crate::interface::InterchainTokenServiceInterface;
 use crate::storage::{self, TokenIdConfigValue};
 use crate::token_metadata::TokenMetadataExt;
 use crate::types::{
-    DeployInterchainToken, HubMessage, InterchainTransfer, Message, TokenManagerType,
+    DeployInterchainToken, FlowLimitConfig, FlowLimitMode, HubMessage, InterchainTransfer,
+    LinkToken, Message, RegisterTokenMetadata, TokenManagerType,
 };
 use crate::{deployer, flow_limit, token_handler, token_id, token_metadata};
 
@@ -133,6 +135,10 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         token_id::canonical_interchain_token_id(env, Self::chain_name_hash(env), token_address)
     }
 
+    fn linked_token_id(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32> {
+        token_id::linked_token_id(env, Self::chain_name_hash(env), deployer, salt)
+    }
+
     fn interchain_token_address(env: &Env, token_id: BytesN<32>) -> Address {
         deployer::interchain_token_address(env, token_id)
     }
@@ -157,6 +163,10 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         flow_limit::flow_limit(env, token_id)
     }
 
+    fn flow_limit_config(env: &Env, token_id: BytesN<32>) -> FlowLimitConfig {
+        flow_limit::flow_limit_config(env, token_id)
+    }
+
     fn flow_out_amount(env: &Env, token_id: BytesN<32>) -> i128 {
         flow_limit::flow_out_amount(env, token_id)
     }
@@ -165,15 +175,57 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         flow_limit::flow_in_amount(env, token_id)
     }
 
-    #[only_operator]
+    fn is_flow_limiter(env: &Env, token_id: BytesN<32>, flow_limiter: Address) -> bool {
+        flow_limit::is_flow_limiter(env, token_id, flow_limiter)
+    }
+
+    fn add_flow_limiter(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError> {
+        Self::ensure_flow_limit_manager(env, caller, token_id.clone())?;
+
+        let _ = Self::token_id_config(env, token_id.clone())?;
+
+        flow_limit::add_flow_limiter(env, token_id, flow_limiter)
+    }
+
+    fn remove_flow_limiter(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError> {
+        Self::ensure_flow_limit_manager(env, caller, token_id.clone())?;
+
+        flow_limit::remove_flow_limiter(env, token_id, flow_limiter)
+    }
+
     fn set_flow_limit(
         env: &Env,
+        caller: Address,
         token_id: BytesN<32>,
         flow_limit: Option<i128>,
     ) -> Result<(), ContractError> {
+        Self::ensure_flow_limit_manager(env, caller, token_id.clone())?;
+
         flow_limit::set_flow_limit(env, token_id, flow_limit)
     }
 
+    fn set_flow_limit_config(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        epoch_duration: u64,
+        mode: FlowLimitMode,
+    ) -> Result<(), ContractError> {
+        Self::ensure_flow_limit_manager(env, caller, token_id.clone())?;
+
+        flow_limit::set_flow_limit_config(env, token_id, epoch_duration, mode)
+    }
+
     #[when_not_paused]
     fn deploy_interchain_token(
         env: &Env,
@@ -191,7 +243,15 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
 
         token_metadata.validate()?;
 
-        let token_address = Self::deploy_token(env, token_id.clone(), token_metadata, minter)?;
+        let flow_limiter = minter.clone().unwrap_or_else(|| caller.clone());
+
+        let token_address = Self::deploy_token(
+            env,
+            token_id.clone(),
+            token_metadata,
+            minter,
+            Some(flow_limiter),
+        )?;
 
         if initial_supply > 0 {
             StellarAssetClient::new(env, &token_address).mint(&caller, &initial_supply);
@@ -257,6 +317,124 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
         Ok(token_id)
     }
 
+    #[when_not_paused]
+    fn register_token_metadata(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        gas_token: Option<Token>,
+    ) -> Result<(), ContractError> {
+        spender.require_auth();
+
+        let TokenMetadata { decimal, .. } =
+            token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
+
+        TokenMetadataRegisteredEvent {
+            token_address: token_address.clone(),
+            decimals: decimal,
+        }
+        .emit(env);
+
+        let payload = HubMessage::RegisterTokenMetadata(RegisterTokenMetadata {
+            token_address: token_address.to_string_bytes(),
+            decimals: u8::try_from(decimal).map_err(|_| ContractError::InvalidTokenDecimals)?,
+        })
+        .abi_encode(env)?;
+
+        Self::pay_gas_and_call_hub(env, spender, payload, gas_token);
+
+        Ok(())
+    }
+
+    #[when_not_paused]
+    fn register_custom_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        token_address: Address,
+        token_manager_type: TokenManagerType,
+    ) -> Result<BytesN<32>, ContractError> {
+        deployer.require_auth();
+
+        ensure!(
+            token_manager_type != TokenManagerType::NativeInterchainToken,
+            ContractError::InvalidTokenManagerType
+        );
+
+        // Validates the token address and it's associated token metadata
+        let _ =
+            token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
+
+        let token_id = Self::linked_token_id(env, deployer.clone(), salt);
+
+        Self::ensure_token_not_registered(env, token_id.clone())?;
+
+        flow_limit::add_flow_limiter(env, token_id.clone(), deployer)?;
+
+        let _: Address =
+            Self::deploy_token_manager(env, token_id.clone(), token_address, token_manager_type);
+
+        Ok(token_id)
+    }
+
+    #[when_not_paused]
+    fn link_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        destination_token_address: Bytes,
+        token_manager_type: TokenManagerType,
+        link_params: Option<Bytes>,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError> {
+        deployer.require_auth();
+
+        ensure!(
+            token_manager_type != TokenManagerType::NativeInterchainToken,
+            ContractError::InvalidTokenManagerType
+        );
+
+        ensure!(
+            !destination_token_address.is_empty(),
+            ContractError::InvalidDestinationAddress
+        );
+
+        ensure!(
+            destination_chain != Self::chain_name(env),
+            ContractError::InvalidDestinationChain
+        );
+
+        if let Some(ref link_params) = link_params {
+            ensure!(!link_params.is_empty(), ContractError::InvalidData);
+        }
+
+        let token_id = Self::linked_token_id(env, deployer.clone(), salt);
+        let token_address = Self::token_id_config(env, token_id.clone())?.token_address;
+
+        LinkTokenStartedEvent {
+            token_id: token_id.clone(),
+            destination_chain: destination_chain.clone(),
+            source_token_address: token_address.clone(),
+            destination_token_address: destination_token_address.clone(),
+            token_manager_type,
+            params: link_params.clone(),
+        }
+        .emit(env);
+
+        let message = Message::LinkToken(LinkToken {
+            token_id: token_id.clone(),
+            token_manager_type,
+            source_token_address: token_address.to_string_bytes(),
+            destination_token_address,
+            params: link_params,
+        });
+
+        Self::pay_gas_and_call_contract(env, deployer, destination_chain, message, gas_token)?;
+
+        Ok(token_id)
+    }
+
     #[when_not_paused]
     fn interchain_transfer(
         env: &Env,
@@ -281,7 +459,7 @@ impl InterchainTokenServiceInterface for InterchainTokenService {
 
         caller.require_auth();
 
-        token_handler::take_token(
+        let amount = token_handler::take_token(
             env,
             &caller,
             Self::token_id_config_with_extended_ttl(env, token_id.clone())?,
@@ -327,38 +505,44 @@ impl InterchainTokenService {
             ContractError::UntrustedChain
         );
 
-        let gateway = AxelarGatewayMessagingClient::new(env, &Self::gateway(env));
-        let gas_service = AxelarGasServiceClient::new(env, &Self::gas_service(env));
-
         let payload = HubMessage::SendToHub {
             destination_chain,
             message,
         }
         .abi_encode(env)?;
 
+        Self::pay_gas_and_call_hub(env, caller, payload, gas_token);
+
+        Ok(())
+    }
+
+    /// Sends the `payload` to the ITS Hub, paying for gas with the `gas_token` if provided.
+    fn pay_gas_and_call_hub(env: &Env, caller: Address, payload: Bytes, gas_token: Option<Token>) {
+        let gateway = AxelarGatewayMessagingClient::new(env, &Self::gateway(env));
+        let gas_service = AxelarGasServiceClient::new(env, &Self::gas_service(env));
+
         let hub_chain = Self::its_hub_chain_name(env);
         let hub_address = Self::its_hub_address(env);
 
+        let message_id = gateway.call_contract(
+            &env.current_contract_address(),
+            &hub_chain,
+            &hub_address,
+            &payload,
+        );
+
         if let Some(gas_token) = gas_token {
             gas_service.pay_gas(
                 &env.current_contract_address(),
+                &message_id,
                 &hub_chain,
                 &hub_address,
                 &payload,
                 &caller,
                 &gas_token,
-                &Bytes::new(env),
+                &None,
             );
         }
-
-        gateway.call_contract(
-            &env.current_contract_address(),
-            &hub_chain,
-            &hub_address,
-            &payload,
-        );
-
-        Ok(())
     }
 
     /// Validate that the message is coming from the ITS Hub and decode the message
@@ -480,7 +664,7 @@ impl InterchainTokenService {
             token_id: token_id.clone(),
             name: name.clone(),
             symbol: symbol.clone(),
-            decimals: decimal as u8,
+            decimals: u8::try_from(decimal).map_err(|_| ContractError::InvalidTokenDecimals)?,
             minter: None,
         });
 
@@ -594,7 +778,41 @@ impl InterchainTokenService {
         // Note: attempt to convert a byte string which doesn't represent a valid Soroban address fails at the Host level
         let minter = minter.map(|m| Address::from_string_bytes(&m));
 
-        let _: Address = Self::deploy_token(env, token_id, token_metadata, minter)?;
+        let _: Address = Self::deploy_token(env, token_id, token_metadata, minter.clone(), minter)?;
+
+        Ok(())
+    }
+
+    /// Deploys a token manager of the linked type for the existing destination token.
+    ///
+    /// Link params are rejected, since Stellar token managers don't have an operator to set.
+    fn execute_link_token_message(
+        env: &Env,
+        LinkToken {
+            token_id,
+            token_manager_type,
+            destination_token_address,
+            params,
+            ..
+        }: LinkToken,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            token_manager_type != TokenManagerType::NativeInterchainToken,
+            ContractError::InvalidTokenManagerType
+        );
+        ensure!(params.is_none(), ContractError::InvalidData);
+
+        // Note: attempt to convert a byte string which doesn't represent a valid Soroban address fails at the Host level
+        let token_address = Address::from_string_bytes(&destination_token_address);
+
+        // Validates the token address and it's associated token metadata
+        let _ =
+            token_metadata::token_metadata(env, &token_address, &Self::native_token_address(env))?;
+
+        Self::ensure_token_not_registered(env, token_id.clone())?;
+
+        let _: Address =
+            Self::deploy_token_manager(env, token_id, token_address, token_manager_type);
 
         Ok(())
     }
@@ -632,14 +850,20 @@ impl InterchainTokenService {
     /// * `token_id` - The token ID for the interchain token being deployed.
     /// * `token_metadata` - The metadata for the interchain token being deployed.
     /// * `minter` - An optional address of an additional minter for the interchain token being deployed.
+    /// * `flow_limiter` - An optional address of the initial flow limiter for the interchain token being deployed.
     fn deploy_token(
         env: &Env,
         token_id: BytesN<32>,
         token_metadata: TokenMetadata,
         minter: Option<Address>,
+        flow_limiter: Option<Address>,
     ) -> Result<Address, ContractError> {
         Self::ensure_token_not_registered(env, token_id.clone())?;
 
+        if let Some(flow_limiter) = flow_limiter {
+            flow_limit::add_flow_limiter(env, token_id.clone(), flow_limiter)?;
+        }
+
         let token_address = deployer::deploy_interchain_token(
             env,
             Self::interchain_token_wasm_hash(env),
@@ -662,6 +886,23 @@ impl InterchainTokenService {
         Ok(token_address)
     }
 
+    /// Ensures that the `caller` is allowed to manage the flow limit of the token, i.e. it is either the operator or a flow limiter of the token.
+    fn ensure_flow_limit_manager(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        ensure!(
+            caller == interfaces::operator(env)
+                || flow_limit::is_flow_limiter(env, token_id, caller),
+            ContractError::NotFlowLimiter
+        );
+
+        Ok(())
+    }
+
     fn ensure_token_not_registered(env: &Env, token_id: BytesN<32>) -> Result<(), ContractError> {
         ensure!(
             storage::try_token_id_config(env, token_id).is_none(),
@@ -695,6 +936,7 @@ impl CustomAxelarExecutable for InterchainTokenService {
                 Self::execute_transfer_message(env, &source_chain, message_id, message)
             }
             Message::DeployInterchainToken(message) => Self::execute_deploy_message(env, message),
+            Message::LinkToken(message) => Self::execute_link_token_message(env, message),
         }?;
 
         Ok(())
@@ -34,4 +34,9 @@ pub enum ContractError {
     ContractPaused = 28,
     InvalidInitialSupply = 29,
     TokenInvocationError = 30,
+    InvalidTokenManagerType = 31,
+    NotFlowLimiter = 32,
+    FlowLimiterAlreadySet = 33,
+    FlowLimiterNotSet = 34,
+    InvalidFlowLimitEpoch = 35,
 }
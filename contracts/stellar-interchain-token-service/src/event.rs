@@ -3,7 +3,7 @@ use core::fmt::Debug;
 use soroban_sdk::{Address, Bytes, BytesN, String};
 use stellar_axelar_std::IntoEvent;
 
-use crate::types::TokenManagerType;
+use crate::types::{FlowLimitMode, TokenManagerType};
 
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct TrustedChainSetEvent {
@@ -22,6 +22,25 @@ pub struct FlowLimitSetEvent {
     pub flow_limit: Option<i128>,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct FlowLimitConfigSetEvent {
+    pub token_id: BytesN<32>,
+    pub epoch_duration: u64,
+    pub mode: FlowLimitMode,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct FlowLimiterAddedEvent {
+    pub token_id: BytesN<32>,
+    pub flow_limiter: Address,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct FlowLimiterRemovedEvent {
+    pub token_id: BytesN<32>,
+    pub flow_limiter: Address,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct InterchainTokenDeployedEvent {
     pub token_id: BytesN<32>,
@@ -52,6 +71,23 @@ pub struct InterchainTokenDeploymentStartedEvent {
     pub minter: Option<Address>,
 }
 
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct TokenMetadataRegisteredEvent {
+    pub token_address: Address,
+    pub decimals: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, IntoEvent)]
+pub struct LinkTokenStartedEvent {
+    pub token_id: BytesN<32>,
+    pub destination_chain: String,
+    pub source_token_address: Address,
+    pub destination_token_address: Bytes,
+    pub token_manager_type: TokenManagerType,
+    #[data]
+    pub params: Option<Bytes>,
+}
+
 #[derive(Debug, PartialEq, Eq, IntoEvent)]
 pub struct InterchainTransferSentEvent {
     pub token_id: BytesN<32>,
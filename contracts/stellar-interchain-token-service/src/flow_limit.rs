@@ -1,12 +1,18 @@
-use soroban_sdk::{BytesN, Env};
+use soroban_sdk::{Address, BytesN, Env};
 use stellar_axelar_std::ensure;
 use stellar_axelar_std::events::Event;
 
 use crate::error::ContractError;
-use crate::event::FlowLimitSetEvent;
+use crate::event::{
+    FlowLimitConfigSetEvent, FlowLimitSetEvent, FlowLimiterAddedEvent, FlowLimiterRemovedEvent,
+};
 use crate::storage::{self, FlowKey};
+use crate::types::{FlowLimitConfig, FlowLimitMode};
 
-const EPOCH_TIME: u64 = 6 * 60 * 60; // 6 hours in seconds = 21600
+/// The epoch duration used for tokens without a flow limit config.
+const DEFAULT_EPOCH_TIME: u64 = 6 * 60 * 60; // 6 hours in seconds = 21600
+const MAX_EPOCH_TIME: u64 = 7 * 24 * 60 * 60; // 7 days in seconds = 604800
+const LEDGER_TIME: u64 = 5;
 
 pub enum FlowDirection {
     /// An interchain transfer coming in to this chain from another chain
@@ -16,40 +22,126 @@ pub enum FlowDirection {
 }
 
 impl FlowDirection {
-    fn flow(&self, env: &Env, token_id: BytesN<32>) -> i128 {
+    fn epoch_flow(&self, env: &Env, flow_key: FlowKey) -> i128 {
         match self {
-            Self::In => flow_in_amount(env, token_id),
-            Self::Out => flow_out_amount(env, token_id),
+            Self::In => storage::try_flow_in(env, flow_key),
+            Self::Out => storage::try_flow_out(env, flow_key),
         }
+        .unwrap_or(0)
+    }
+
+    /// Returns the effective flow within the current window of the token.
+    ///
+    /// In [`FlowLimitMode::SlidingWindow`] mode, the flow of the previous epoch is included,
+    /// weighted by the fraction of the current epoch that is still remaining.
+    fn flow(&self, env: &Env, token_id: BytesN<32>) -> i128 {
+        let FlowLimitConfig {
+            epoch_duration,
+            mode,
+        } = flow_limit_config(env, token_id.clone());
+        let epoch = current_epoch(env, epoch_duration);
+
+        let flow = self
+            .epoch_flow(
+                env,
+                FlowKey {
+                    token_id: token_id.clone(),
+                    epoch,
+                },
+            )
+            .saturating_add(self.legacy_epoch_flow(env, token_id.clone(), epoch, epoch_duration));
+
+        match mode {
+            FlowLimitMode::Fixed => flow,
+            FlowLimitMode::SlidingWindow => {
+                let previous_flow = epoch
+                    .checked_sub(epoch_duration)
+                    .map_or(0, |epoch| self.epoch_flow(env, FlowKey { token_id, epoch }));
+                let remaining = epoch_duration - (env.ledger().timestamp() - epoch);
+
+                flow.saturating_add(weighted_flow(previous_flow, remaining, epoch_duration))
+            }
+        }
+    }
+
+    /// Returns the flow of the current epoch recorded under its legacy key, i.e. the epoch index instead of its start timestamp.
+    ///
+    /// Flow buckets recorded before the upgrade to epoch start timestamps are keyed by the index of the default epoch,
+    /// so they're still counted for the epoch in progress during the upgrade. They expire with that epoch.
+    fn legacy_epoch_flow(
+        &self,
+        env: &Env,
+        token_id: BytesN<32>,
+        epoch: u64,
+        epoch_duration: u64,
+    ) -> i128 {
+        let legacy_epoch = epoch / DEFAULT_EPOCH_TIME;
+
+        // The legacy bucket only covers the current epoch with the default epoch duration
+        if epoch_duration != DEFAULT_EPOCH_TIME || legacy_epoch == epoch {
+            return 0;
+        }
+
+        self.epoch_flow(
+            env,
+            FlowKey {
+                token_id,
+                epoch: legacy_epoch,
+            },
+        )
     }
 
     fn reverse_flow(&self, env: &Env, token_id: BytesN<32>) -> i128 {
         match self {
-            Self::In => flow_out_amount(env, token_id),
-            Self::Out => flow_in_amount(env, token_id),
+            Self::In => Self::Out.flow(env, token_id),
+            Self::Out => Self::In.flow(env, token_id),
         }
     }
 
-    fn update_flow(&self, env: &Env, token_id: BytesN<32>, new_flow: i128) {
+    /// Adds `flow_amount` to the flow bucket of the current epoch, and extends its TTL
+    /// so that it remains available while it's still part of a window.
+    fn update_flow(
+        &self,
+        env: &Env,
+        token_id: BytesN<32>,
+        flow_amount: i128,
+    ) -> Result<(), ContractError> {
+        let epoch_duration = flow_limit_config(env, token_id.clone()).epoch_duration;
         let flow_key = FlowKey {
             token_id,
-            epoch: current_epoch(env),
+            epoch: current_epoch(env, epoch_duration),
         };
 
+        let new_flow = self
+            .epoch_flow(env, flow_key.clone())
+            .checked_add(flow_amount)
+            .ok_or(ContractError::FlowAmountOverflow)?;
+
+        // Keep the bucket alive for the current epoch and the following one, where it's used as the previous epoch
+        let ttl = (2 * epoch_duration).div_ceil(LEDGER_TIME) as u32;
+
         match self {
-            Self::In => storage::set_flow_in(env, flow_key, &new_flow),
-            Self::Out => storage::set_flow_out(env, flow_key, &new_flow),
+            Self::In => {
+                storage::set_flow_in(env, flow_key.clone(), &new_flow);
+                storage::extend_flow_in_ttl(env, flow_key, ttl, ttl);
+            }
+            Self::Out => {
+                storage::set_flow_out(env, flow_key.clone(), &new_flow);
+                storage::extend_flow_out_ttl(env, flow_key, ttl, ttl);
+            }
         };
+
+        Ok(())
     }
 
     /// Adds flow amount in the specified direction (in/out) for a token.
     /// Flow amounts are stored in temporary storage since they only need to persist for
-    /// the 6-hour epoch duration.
+    /// as long as they're part of the token's flow limit window.
     ///
     /// Checks that:
     /// - Flow amount doesn't exceed the flow limit
     /// - Adding flows won't cause overflow
-    /// - Net flow (outgoing minus incoming flow) doesn't exceed the limit
+    /// - Net flow (outgoing minus incoming flow) within the window doesn't exceed the limit
     pub fn add_flow(
         &self,
         env: &Env,
@@ -74,14 +166,60 @@ impl FlowDirection {
         // Equivalent to flow_amount + flow - reverse_flow <= flow_limit
         ensure!(new_flow <= max_allowed, ContractError::FlowLimitExceeded);
 
-        self.update_flow(env, token_id, new_flow);
-
-        Ok(())
+        self.update_flow(env, token_id, flow_amount)
     }
 }
 
-fn current_epoch(env: &Env) -> u64 {
-    env.ledger().timestamp() / EPOCH_TIME
+/// Returns `flow * numerator / denominator` without overflowing, rounded down.
+const fn weighted_flow(flow: i128, numerator: u64, denominator: u64) -> i128 {
+    let (numerator, denominator) = (numerator as i128, denominator as i128);
+
+    // `flow % denominator * numerator` can't overflow since both are bounded by `MAX_EPOCH_TIME`
+    flow / denominator * numerator + flow % denominator * numerator / denominator
+}
+
+/// Returns the start timestamp of the current epoch.
+fn current_epoch(env: &Env, epoch_duration: u64) -> u64 {
+    let timestamp = env.ledger().timestamp();
+
+    timestamp - timestamp % epoch_duration
+}
+
+pub fn flow_limit_config(env: &Env, token_id: BytesN<32>) -> FlowLimitConfig {
+    storage::try_flow_limit_config(env, token_id).unwrap_or(FlowLimitConfig {
+        epoch_duration: DEFAULT_EPOCH_TIME,
+        mode: FlowLimitMode::Fixed,
+    })
+}
+
+pub fn set_flow_limit_config(
+    env: &Env,
+    token_id: BytesN<32>,
+    epoch_duration: u64,
+    mode: FlowLimitMode,
+) -> Result<(), ContractError> {
+    ensure!(
+        epoch_duration > 0 && epoch_duration <= MAX_EPOCH_TIME,
+        ContractError::InvalidFlowLimitEpoch
+    );
+
+    storage::set_flow_limit_config(
+        env,
+        token_id.clone(),
+        &FlowLimitConfig {
+            epoch_duration,
+            mode,
+        },
+    );
+
+    FlowLimitConfigSetEvent {
+        token_id,
+        epoch_duration,
+        mode,
+    }
+    .emit(env);
+
+    Ok(())
 }
 
 pub fn flow_limit(env: &Env, token_id: BytesN<32>) -> Option<i128> {
@@ -110,24 +248,56 @@ pub fn set_flow_limit(
     Ok(())
 }
 
+pub fn is_flow_limiter(env: &Env, token_id: BytesN<32>, flow_limiter: Address) -> bool {
+    storage::is_flow_limiter(env, token_id, flow_limiter)
+}
+
+pub fn add_flow_limiter(
+    env: &Env,
+    token_id: BytesN<32>,
+    flow_limiter: Address,
+) -> Result<(), ContractError> {
+    ensure!(
+        !is_flow_limiter(env, token_id.clone(), flow_limiter.clone()),
+        ContractError::FlowLimiterAlreadySet
+    );
+
+    storage::set_flow_limiter_status(env, token_id.clone(), flow_limiter.clone());
+
+    FlowLimiterAddedEvent {
+        token_id,
+        flow_limiter,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
+pub fn remove_flow_limiter(
+    env: &Env,
+    token_id: BytesN<32>,
+    flow_limiter: Address,
+) -> Result<(), ContractError> {
+    ensure!(
+        is_flow_limiter(env, token_id.clone(), flow_limiter.clone()),
+        ContractError::FlowLimiterNotSet
+    );
+
+    storage::remove_flow_limiter_status(env, token_id.clone(), flow_limiter.clone());
+
+    FlowLimiterRemovedEvent {
+        token_id,
+        flow_limiter,
+    }
+    .emit(env);
+
+    Ok(())
+}
+
 pub fn flow_out_amount(env: &Env, token_id: BytesN<32>) -> i128 {
-    storage::try_flow_out(
-        env,
-        FlowKey {
-            token_id,
-            epoch: current_epoch(env),
-        },
-    )
-    .unwrap_or(0)
+    FlowDirection::Out.flow(env, token_id)
 }
 
 pub fn flow_in_amount(env: &Env, token_id: BytesN<32>) -> i128 {
-    storage::try_flow_in(
-        env,
-        FlowKey {
-            token_id,
-            epoch: current_epoch(env),
-        },
-    )
-    .unwrap_or(0)
+    FlowDirection::In.flow(env, token_id)
 }
@@ -7,7 +7,7 @@ use stellar_axelar_std::interfaces::{
 use stellar_axelar_std::types::Token;
 
 use crate::error::ContractError;
-use crate::types::TokenManagerType;
+use crate::types::{FlowLimitConfig, FlowLimitMode, TokenManagerType};
 
 #[contractclient(name = "InterchainTokenServiceClient")]
 pub trait InterchainTokenServiceInterface:
@@ -74,6 +74,16 @@ pub trait InterchainTokenServiceInterface:
     /// - A `BytesN<32>` value representing the computed deployment salt.
     fn canonical_interchain_token_id(env: &Env, token_address: Address) -> BytesN<32>;
 
+    /// Returns the unique identifier for a custom token linked by the `deployer` with the `salt`.
+    ///
+    /// # Parameters
+    /// - `deployer`: The address that registers and links the custom token.
+    /// - `salt`: A unique value used to generate the token ID.
+    ///
+    /// # Returns
+    /// - A `BytesN<32>` value representing the token's unique ID.
+    fn linked_token_id(env: &Env, deployer: Address, salt: BytesN<32>) -> BytesN<32>;
+
     /// Returns the predicted address of the native interchain token associated with the specified token ID.
     ///
     /// # Arguments
@@ -105,35 +115,116 @@ pub trait InterchainTokenServiceInterface:
     /// Returns `None` if no limit is set.
     fn flow_limit(env: &Env, token_id: BytesN<32>) -> Option<i128>;
 
-    /// Returns the amount that has flowed out of the chain to other chains during the current epoch
+    /// Returns the flow limit window configuration for the token associated with the specified token ID.
+    /// Defaults to fixed epochs of 6 hours if no configuration is set.
+    fn flow_limit_config(env: &Env, token_id: BytesN<32>) -> FlowLimitConfig;
+
+    /// Returns the amount that has flowed out of the chain to other chains within the current flow limit window
     /// for the token associated with the specified token ID.
     fn flow_out_amount(env: &Env, token_id: BytesN<32>) -> i128;
 
-    /// Retrieves the amount that has flowed into the chain from other chains during the current epoch
+    /// Retrieves the amount that has flowed into the chain from other chains within the current flow limit window
     /// for the token associated with the specified token ID.
     fn flow_in_amount(env: &Env, token_id: BytesN<32>) -> i128;
 
+    /// Returns whether the address is a flow limiter for the token associated with the specified token ID.
+    fn is_flow_limiter(env: &Env, token_id: BytesN<32>, flow_limiter: Address) -> bool;
+
+    /// Adds a flow limiter for a token, allowing it to set the flow limit of that token.
+    ///
+    /// The caller of [`Self::deploy_interchain_token`] (or the minter, if provided) and of
+    /// [`Self::register_custom_token`] is added as the initial flow limiter of the token.
+    ///
+    /// # Arguments
+    /// - `caller`: Either the operator or an existing flow limiter of the token.
+    /// - `token_id`: Unique identifier of the token.
+    /// - `flow_limiter`: The address to add as flow limiter.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotFlowLimiter`]: If the caller is neither the operator nor a flow limiter of the token.
+    /// - [`ContractError::InvalidTokenId`]: If the token ID is not registered.
+    /// - [`ContractError::FlowLimiterAlreadySet`]: If the address is already a flow limiter of the token.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn add_flow_limiter(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError>;
+
+    /// Removes a flow limiter for a token.
+    ///
+    /// # Arguments
+    /// - `caller`: Either the operator or a flow limiter of the token.
+    /// - `token_id`: Unique identifier of the token.
+    /// - `flow_limiter`: The flow limiter to remove.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotFlowLimiter`]: If the caller is neither the operator nor a flow limiter of the token.
+    /// - [`ContractError::FlowLimiterNotSet`]: If the address is not a flow limiter of the token.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn remove_flow_limiter(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    ) -> Result<(), ContractError>;
+
     /// Sets or updates the flow limit for a token.
     ///
-    /// Flow limit controls how many tokens can flow in/out during a single epoch.
+    /// Flow limit controls how many tokens can flow in/out within the token's flow limit window.
     /// Setting the limit to `None` disables flow limit checks for the token.
     /// Setting the limit to 0 effectively freezes the token by preventing any flow.
     ///
     /// # Arguments
+    /// - `caller`: Either the operator or a flow limiter of the token.
     /// - `token_id`: Unique identifier of the token.
     /// - `flow_limit`: The new flow limit value. Must be positive if Some.
     ///
     /// # Errors
+    /// - [`ContractError::NotFlowLimiter`]: If the caller is neither the operator nor a flow limiter of the token.
     /// - [`ContractError::InvalidFlowLimit`]: If the provided flow limit is not positive.
     ///
     /// # Authorization
-    /// - [`OperatableInterface::operator`] must authorize.
+    /// - The `caller` must authorize.
     fn set_flow_limit(
         env: &Env,
+        caller: Address,
         token_id: BytesN<32>,
         flow_limit: Option<i128>,
     ) -> Result<(), ContractError>;
 
+    /// Sets the flow limit window configuration for a token.
+    ///
+    /// In [`FlowLimitMode::Fixed`] mode, the flow resets at the start of every epoch. In
+    /// [`FlowLimitMode::SlidingWindow`] mode, the flow of the previous epoch is carried over, weighted
+    /// by the remaining fraction of the current epoch, so the limit can't be exceeded by transferring
+    /// around an epoch boundary.
+    ///
+    /// # Arguments
+    /// - `caller`: Either the operator or a flow limiter of the token.
+    /// - `token_id`: Unique identifier of the token.
+    /// - `epoch_duration`: The length of an epoch in seconds. Must be positive and at most 7 days.
+    /// - `mode`: How the flow is accounted for within the window.
+    ///
+    /// # Errors
+    /// - [`ContractError::NotFlowLimiter`]: If the caller is neither the operator nor a flow limiter of the token.
+    /// - [`ContractError::InvalidFlowLimitEpoch`]: If the epoch duration is out of range.
+    ///
+    /// # Authorization
+    /// - The `caller` must authorize.
+    fn set_flow_limit_config(
+        env: &Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        epoch_duration: u64,
+        mode: FlowLimitMode,
+    ) -> Result<(), ContractError>;
+
     /// Deploys a new interchain token on the current chain with specified metadata and optional
     /// initial supply. If initial supply is provided, it is minted to the caller. The
     /// caller can also specify an optional minter address for the interchain token.
@@ -231,6 +322,98 @@ pub trait InterchainTokenServiceInterface:
         gas_token: Option<Token>,
     ) -> Result<BytesN<32>, ContractError>;
 
+    /// Registers the metadata of an existing token with the ITS Hub.
+    ///
+    /// This is required before the token can be linked to a token on another chain, so that
+    /// the ITS Hub can scale transfer amounts between tokens with different decimals.
+    /// Anyone can call this for any token.
+    ///
+    /// # Arguments
+    /// * `token_address` - The address of the token whose metadata is registered.
+    /// * `spender` - The spender of the cross-chain gas.
+    /// * `gas_token` - An optional gas token used to pay for gas of the cross-chain call.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenAddress`]: If the token address is not a valid token.
+    /// - [`ContractError::InvalidTokenDecimals`]: If the token decimals don't fit in a `u8`.
+    /// - Errors propagated from `token_metadata`.
+    ///
+    /// # Authorization
+    /// - `spender` needs to authorize `pay_gas` call to the gas service.
+    fn register_token_metadata(
+        env: &Env,
+        token_address: Address,
+        spender: Address,
+        gas_token: Option<Token>,
+    ) -> Result<(), ContractError>;
+
+    /// Registers an existing token as a custom interchain token, by deploying a token manager of the given type for it.
+    ///
+    /// The token can then be linked to existing tokens on other chains with [`Self::link_token`],
+    /// using the same `deployer` and `salt`.
+    ///
+    /// # Arguments
+    /// * `deployer` - The address registering the custom token.
+    /// * `salt` - A unique value used to generate the token ID.
+    /// * `token_address` - The address of the existing token.
+    /// * `token_manager_type` - The type of token manager to deploy for the token.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenManagerType`]: If the token manager type is [`TokenManagerType::NativeInterchainToken`].
+    /// - [`ContractError::InvalidTokenAddress`]: If the token address is not a valid token.
+    /// - [`ContractError::TokenAlreadyRegistered`]: If the token ID is already registered.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn register_custom_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        token_address: Address,
+        token_manager_type: TokenManagerType,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    /// Links a custom token registered with [`Self::register_custom_token`] to an existing token on the destination chain.
+    ///
+    /// A token manager of `token_manager_type` is deployed for `destination_token_address` on the destination chain.
+    /// The token metadata of both tokens must have been registered with the ITS Hub beforehand.
+    ///
+    /// # Arguments
+    /// * `deployer` - The address that registered the custom token.
+    /// * `salt` - The salt used to register the custom token.
+    /// * `destination_chain` - The name of the destination chain.
+    /// * `destination_token_address` - The address of the existing token on the destination chain.
+    /// * `token_manager_type` - The type of token manager to deploy on the destination chain.
+    /// * `link_params` - Optional parameters for the destination token manager, such as its operator.
+    /// * `gas_token` - An optional gas token used to pay for gas of the cross-chain call.
+    ///
+    /// # Returns
+    /// - `Ok(BytesN<32>)`: Returns the token ID.
+    ///
+    /// # Errors
+    /// - [`ContractError::InvalidTokenManagerType`]: If the token manager type is [`TokenManagerType::NativeInterchainToken`].
+    /// - [`ContractError::InvalidDestinationAddress`]: If the destination token address is empty.
+    /// - [`ContractError::InvalidDestinationChain`]: If the `destination_chain` is the current chain.
+    /// - [`ContractError::InvalidData`]: If `link_params` is provided but empty.
+    /// - [`ContractError::InvalidTokenId`]: If the custom token has not been registered.
+    /// - Any error propagated from `pay_gas_and_call_contract`.
+    ///
+    /// # Authorization
+    /// - The `deployer` must authorize.
+    fn link_token(
+        env: &Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        destination_chain: String,
+        destination_token_address: Bytes,
+        token_manager_type: TokenManagerType,
+        link_params: Option<Bytes>,
+        gas_token: Option<Token>,
+    ) -> Result<BytesN<32>, ContractError>;
+
     /// Initiates a cross-chain token transfer.
     ///
     /// Takes tokens from the caller on the source chain and initiates a transfer
@@ -246,11 +429,12 @@ pub trait InterchainTokenServiceInterface:
     /// - `destination_chain`: The chain to which tokens will be transferred.
     /// - `destination_address`: The recipient address on the destination chain.
     /// - `amount`: The amount of tokens to transfer. Must be greater than 0.
+    ///   For [`TokenManagerType::LockUnlockFee`] tokens, the amount received by the token manager after the transfer fee is sent instead.
     /// - `data`: Optional data to be handled by the destination address if it's a contract.
     /// - `gas_token`: An optional gas token used to pay for cross-chain message execution.
     ///
     /// # Errors
-    /// - [`ContractError::InvalidAmount`]: If amount is not greater than 0.
+    /// - [`ContractError::InvalidAmount`]: If amount, or the amount received after the transfer fee, is not greater than 0.
     /// - [`ContractError::FlowLimitExceeded`]: If transfer would exceed flow limits.
     /// - Any error propagated from `pay_gas_and_call_contract`.
     ///
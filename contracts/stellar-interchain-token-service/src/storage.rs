@@ -1,7 +1,7 @@
 use soroban_sdk::{contracttype, Address, BytesN, String};
 use stellar_axelar_std::contractstorage;
 
-use crate::types::TokenManagerType;
+use crate::types::{FlowLimitConfig, TokenManagerType};
 
 #[contractstorage]
 enum DataKey {
@@ -45,6 +45,17 @@ enum DataKey {
     #[value(i128)]
     FlowLimit { token_id: BytesN<32> },
 
+    #[persistent]
+    #[value(FlowLimitConfig)]
+    FlowLimitConfig { token_id: BytesN<32> },
+
+    #[persistent]
+    #[status]
+    FlowLimiter {
+        token_id: BytesN<32>,
+        flow_limiter: Address,
+    },
+
     #[temporary]
     #[value(i128)]
     FlowOut { flow_key: FlowKey },
@@ -66,5 +77,6 @@ pub struct TokenIdConfigValue {
 #[derive(Clone, Debug)]
 pub struct FlowKey {
     pub token_id: BytesN<32>,
+    /// The start timestamp of the epoch, so that flow buckets stay consistent when the epoch duration changes.
     pub epoch: u64,
 }
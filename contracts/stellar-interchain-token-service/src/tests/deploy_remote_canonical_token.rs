@@ -1,12 +1,13 @@
 use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
 use soroban_sdk::token::{self, StellarAssetClient};
-use soroban_sdk::{Address, Bytes, BytesN, IntoVal, String, Symbol};
+use soroban_sdk::{Address, BytesN, IntoVal, String, Symbol};
 use soroban_token_sdk::metadata::TokenMetadata;
-use stellar_axelar_gas_service::testutils::setup_gas_token;
+use stellar_axelar_gas_service::types::GasMetadata;
+use stellar_axelar_gateway::testutils::last_contract_call_message_id;
 use stellar_axelar_std::types::Token;
 use stellar_axelar_std::{auth_invocation, events};
 
-use super::utils::{setup_env, TokenMetadataExt};
+use super::utils::{setup_env, setup_gas_token, TokenMetadataExt};
 use crate::event::InterchainTokenDeploymentStartedEvent;
 use crate::tests::utils::{
     INTERCHAIN_TOKEN_DEPLOYED_EVENT_IDX, INTERCHAIN_TOKEN_DEPLOYED_WITHOUT_GAS_TOKEN_EVENT_IDX,
@@ -15,9 +16,9 @@ use crate::types::{DeployInterchainToken, HubMessage, Message, TokenManagerType}
 
 #[test]
 fn deploy_remote_canonical_token_succeeds() {
-    let (env, client, _, gas_service, _) = setup_env();
+    let (env, client, gateway_client, gas_service, _) = setup_env();
     let spender = Address::generate(&env);
-    let gas_token = setup_gas_token(&env, &spender);
+    let gas_token = setup_gas_token(&env, &client, &spender);
     let asset = &env.register_stellar_asset_contract_v2(Address::generate(&env));
     let initial_amount = 1;
 
@@ -83,12 +84,13 @@ fn deploy_remote_canonical_token_succeeds() {
         spender,
         gas_service.pay_gas(
             client.address.clone(),
+            last_contract_call_message_id(&env, &gateway_client),
             its_hub_chain,
             its_hub_address,
             payload,
             spender.clone(),
             gas_token.clone(),
-            Bytes::new(&env)
+            None::<GasMetadata>
         ),
         transfer_auth
     );
@@ -149,7 +151,7 @@ fn deploy_remote_canonical_token_succeeds_without_gas_token() {
 fn deploy_remote_canonical_token_succeeds_native_token() {
     let (env, client, _, _, _) = setup_env();
     let spender = Address::generate(&env);
-    let gas_token = setup_gas_token(&env, &spender);
+    let gas_token = setup_gas_token(&env, &client, &spender);
     let token_address = client.native_token_address();
     let destination_chain = String::from_str(&env, "ethereum");
 
@@ -174,7 +176,7 @@ fn deploy_remote_canonical_token_succeeds_native_token() {
 fn deploy_remote_canonical_token_succeeds_without_name_truncation() {
     let (env, client, _, _, _) = setup_env();
     let spender = Address::generate(&env);
-    let gas_token = setup_gas_token(&env, &spender);
+    let gas_token = setup_gas_token(&env, &client, &spender);
 
     let token_metadata = TokenMetadata::new(&env, "name", "symbol", 255);
     let initial_supply = 1;
@@ -1,11 +1,12 @@
 use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
-use soroban_sdk::{Address, Bytes, BytesN, IntoVal, String, Symbol};
+use soroban_sdk::{Address, BytesN, IntoVal, String, Symbol};
 use soroban_token_sdk::metadata::TokenMetadata;
-use stellar_axelar_gas_service::testutils::setup_gas_token;
+use stellar_axelar_gas_service::types::GasMetadata;
+use stellar_axelar_gateway::testutils::last_contract_call_message_id;
 use stellar_axelar_std::types::Token;
 use stellar_axelar_std::{assert_contract_err, auth_invocation, events};
 
-use super::utils::{setup_env, TokenMetadataExt};
+use super::utils::{setup_env, setup_gas_token, TokenMetadataExt};
 use crate::error::ContractError;
 use crate::event::InterchainTokenDeploymentStartedEvent;
 use crate::tests::utils::{
@@ -15,10 +16,10 @@ use crate::types::{DeployInterchainToken, HubMessage, Message};
 
 #[test]
 fn deploy_remote_interchain_token_succeeds() {
-    let (env, client, _, gas_service, _) = setup_env();
+    let (env, client, gateway_client, gas_service, _) = setup_env();
 
     let sender = Address::generate(&env);
-    let gas_token = setup_gas_token(&env, &sender);
+    let gas_token = setup_gas_token(&env, &client, &sender);
     let minter: Option<Address> = None;
     let salt = BytesN::<32>::from_array(&env, &[1; 32]);
     let token_metadata = TokenMetadata::new(&env, "name", "symbol", 6);
@@ -77,12 +78,13 @@ fn deploy_remote_interchain_token_succeeds() {
         sender,
         gas_service.pay_gas(
             client.address.clone(),
+            last_contract_call_message_id(&env, &gateway_client),
             its_hub_chain,
             its_hub_address,
             payload,
             &sender,
             gas_token.clone(),
-            &Bytes::new(&env)
+            None::<GasMetadata>
         ),
         transfer_auth
     );
@@ -158,7 +160,7 @@ fn deploy_remote_interchain_token_fails_when_paused() {
             &Address::generate(&env),
             &BytesN::from_array(&env, &[1; 32]),
             &String::from_str(&env, "ethereum"),
-            &Some(setup_gas_token(&env, &Address::generate(&env)))
+            &Some(setup_gas_token(&env, &client, &Address::generate(&env)))
         ),
         ContractError::ContractPaused
     );
@@ -169,7 +171,7 @@ fn deploy_remote_interchain_token_fails_untrusted_chain() {
     let (env, client, _, _, _) = setup_env();
 
     let sender = Address::generate(&env);
-    let gas_token = setup_gas_token(&env, &sender);
+    let gas_token = setup_gas_token(&env, &client, &sender);
     let minter: Option<Address> = None;
     let salt = BytesN::<32>::from_array(&env, &[1; 32]);
     let token_metadata = TokenMetadata::new(&env, "name", "symbol", 6);
@@ -201,7 +203,7 @@ fn deploy_remote_interchain_token_fails_with_invalid_token_id() {
     let (env, client, _, _, _) = setup_env();
 
     let spender = Address::generate(&env);
-    let gas_token = setup_gas_token(&env, &spender);
+    let gas_token = setup_gas_token(&env, &client, &spender);
     let salt = BytesN::<32>::from_array(&env, &[1; 32]);
 
     let destination_chain = String::from_str(&env, "ethereum");
@@ -222,7 +224,7 @@ fn deploy_remote_token_fails_local_deployment() {
     let (env, client, _, _, _) = setup_env();
 
     let spender = Address::generate(&env);
-    let gas_token = setup_gas_token(&env, &spender);
+    let gas_token = setup_gas_token(&env, &client, &spender);
     let salt = BytesN::<32>::from_array(&env, &[1; 32]);
     let destination_chain = client.chain_name();
 
@@ -1,18 +1,18 @@
 use soroban_sdk::testutils::{Address as _, Ledger as _};
 use soroban_sdk::{vec, Address, Bytes, BytesN, Env, String};
-use stellar_axelar_gas_service::testutils::setup_gas_token;
 use stellar_axelar_gateway::testutils::{approve_gateway_messages, TestSignerSet};
 use stellar_axelar_gateway::types::Message as GatewayMessage;
 use stellar_axelar_gateway::AxelarGatewayClient;
 use stellar_axelar_std::address::AddressExt;
 use stellar_axelar_std::traits::BytesExt;
-use stellar_axelar_std::{assert_auth, assert_contract_err, events};
+use stellar_axelar_std::{assert_auth, assert_auth_err, assert_contract_err, events};
 
-use super::utils::setup_env;
+use super::utils::{setup_env, setup_gas_token};
 use crate::error::ContractError;
-use crate::event::FlowLimitSetEvent;
+use crate::event::{FlowLimitConfigSetEvent, FlowLimitSetEvent};
+use crate::storage::{self, FlowKey};
 use crate::testutils::setup_its_token;
-use crate::types::{HubMessage, InterchainTransfer, Message};
+use crate::types::{FlowLimitConfig, FlowLimitMode, HubMessage, InterchainTransfer, Message};
 use crate::InterchainTokenServiceClient;
 
 struct GatewayConfig<'a> {
@@ -33,6 +33,8 @@ struct ApprovedMessage {
 }
 
 const EPOCH_TIME: u64 = 6 * 60 * 60;
+const MAX_EPOCH_TIME: u64 = 7 * 24 * 60 * 60;
+const SHORT_EPOCH_TIME: u64 = 60 * 60;
 
 const fn dummy_flow_limit() -> i128 {
     1000
@@ -64,7 +66,7 @@ fn setup<'a>() -> (
 
     client
         .mock_all_auths()
-        .set_flow_limit(&token_id, &Some(dummy_flow_limit()));
+        .set_flow_limit(&deployer, &token_id, &Some(dummy_flow_limit()));
 
     (
         env,
@@ -150,11 +152,13 @@ fn set_flow_limit_succeeds() {
     let (env, client, _, _, _) = setup_env();
     let token_id = BytesN::from_array(&env, &[1; 32]);
 
+    let operator = client.operator();
+
     assert_eq!(client.flow_limit(&token_id), None);
 
     assert_auth!(
-        client.operator(),
-        client.set_flow_limit(&token_id, &Some(dummy_flow_limit()))
+        operator,
+        client.set_flow_limit(&operator, &token_id, &Some(dummy_flow_limit()))
     );
     goldie::assert!(events::fmt_last_emitted_event::<FlowLimitSetEvent>(&env));
 
@@ -165,11 +169,13 @@ fn set_flow_limit_succeeds() {
 fn set_flow_limit_to_none_succeeds() {
     let (env, client, _, token) = setup();
 
+    let operator = client.operator();
+
     assert_eq!(client.flow_limit(&token.id), Some(dummy_flow_limit()));
 
     assert_auth!(
-        client.operator(),
-        client.set_flow_limit(&token.id, &None::<i128>)
+        operator,
+        client.set_flow_limit(&operator, &token.id, &None::<i128>)
     );
     goldie::assert!(events::fmt_last_emitted_event::<FlowLimitSetEvent>(&env));
 
@@ -179,9 +185,11 @@ fn set_flow_limit_to_none_succeeds() {
 #[test]
 fn zero_flow_limit_effectively_freezes_token() {
     let (env, client, gateway, token) = setup();
-    let gas_token = setup_gas_token(&env, &token.deployer);
+    let gas_token = setup_gas_token(&env, &client, &token.deployer);
 
-    client.mock_all_auths().set_flow_limit(&token.id, &Some(0));
+    client
+        .mock_all_auths()
+        .set_flow_limit(&token.deployer, &token.id, &Some(0));
 
     let amount = 1;
     let msg = approve_its_transfer(&env, &client, &gateway, &token.id, amount);
@@ -226,7 +234,7 @@ fn set_flow_limit_fails_on_negative_limit() {
     assert_contract_err!(
         client
             .mock_all_auths()
-            .try_set_flow_limit(&token_id, &invalid_limit),
+            .try_set_flow_limit(&client.operator(), &token_id, &invalid_limit),
         ContractError::InvalidFlowLimit
     );
 }
@@ -247,6 +255,46 @@ fn flow_limit_resets_after_epoch() {
     assert_eq!(client.flow_in_amount(&token.id), 0);
 }
 
+#[test]
+fn flow_limit_includes_legacy_epoch_flow() {
+    let (env, client, gateway, token) = setup();
+
+    let timestamp = 1_700_000_000;
+    env.ledger().set_timestamp(timestamp);
+
+    let amount = dummy_flow_limit();
+
+    // Flow recorded under the epoch index before the upgrade to epoch start timestamps
+    env.as_contract(&client.address, || {
+        storage::set_flow_in(
+            &env,
+            FlowKey {
+                token_id: token.id.clone(),
+                epoch: timestamp / EPOCH_TIME,
+            },
+            &amount,
+        );
+    });
+
+    assert_eq!(client.flow_in_amount(&token.id), amount);
+
+    let msg = approve_its_transfer(&env, &client, &gateway, &token.id, 1);
+
+    assert_contract_err!(
+        client.try_execute(
+            &msg.source_chain,
+            &msg.message_id,
+            &msg.source_address,
+            &msg.payload
+        ),
+        ContractError::FlowLimitExceeded
+    );
+
+    env.ledger().set_timestamp(timestamp + EPOCH_TIME);
+
+    assert_eq!(client.flow_in_amount(&token.id), 0);
+}
+
 #[test]
 fn add_flow_in_succeeds() {
     let (env, client, gateway, token) = setup();
@@ -288,7 +336,7 @@ fn add_flow_in_fails_on_exceeding_flow_limit() {
 #[test]
 fn add_flow_out_succeeds() {
     let (env, client, _, token) = setup();
-    let gas_token = setup_gas_token(&env, &token.deployer);
+    let gas_token = setup_gas_token(&env, &client, &token.deployer);
 
     let amount = dummy_flow_limit();
     let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
@@ -316,7 +364,7 @@ fn add_flow_out_succeeds() {
 #[test]
 fn add_flow_out_fails_on_exceeding_flow_limit() {
     let (env, client, _, token) = setup();
-    let gas_token = setup_gas_token(&env, &token.deployer);
+    let gas_token = setup_gas_token(&env, &client, &token.deployer);
 
     let amount = dummy_flow_limit();
     let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
@@ -363,11 +411,11 @@ fn add_flow_fails_on_flow_comparison_overflow() {
 
     for (flow_limit, flow_in, flow_out) in &cases {
         let (env, client, gateway, token) = setup();
-        let gas_token = setup_gas_token(&env, &token.deployer);
+        let gas_token = setup_gas_token(&env, &client, &token.deployer);
 
         client
             .mock_all_auths()
-            .set_flow_limit(&token.id, &Some(*flow_limit));
+            .set_flow_limit(&token.deployer, &token.id, &Some(*flow_limit));
 
         let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
         client
@@ -392,11 +440,11 @@ fn add_flow_fails_on_flow_comparison_overflow() {
 
     for (flow_limit, flow_out, flow_in) in cases {
         let (env, client, gateway, token) = setup();
-        let gas_token = setup_gas_token(&env, &token.deployer);
+        let gas_token = setup_gas_token(&env, &client, &token.deployer);
 
         client
             .mock_all_auths()
-            .set_flow_limit(&token.id, &Some(flow_limit));
+            .set_flow_limit(&token.deployer, &token.id, &Some(flow_limit));
 
         let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
         client
@@ -426,3 +474,204 @@ fn add_flow_fails_on_flow_comparison_overflow() {
         );
     }
 }
+
+#[test]
+fn flow_limit_config_defaults_to_fixed_epochs() {
+    let (_, client, _, token) = setup();
+
+    assert_eq!(
+        client.flow_limit_config(&token.id),
+        FlowLimitConfig {
+            epoch_duration: EPOCH_TIME,
+            mode: FlowLimitMode::Fixed,
+        }
+    );
+}
+
+#[test]
+fn set_flow_limit_config_succeeds() {
+    let (env, client, _, token) = setup();
+
+    let mode = FlowLimitMode::SlidingWindow;
+
+    assert_auth!(
+        token.deployer,
+        client.set_flow_limit_config(&token.deployer, &token.id, &SHORT_EPOCH_TIME, &mode)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<FlowLimitConfigSetEvent>(
+        &env
+    ));
+
+    assert_eq!(
+        client.flow_limit_config(&token.id),
+        FlowLimitConfig {
+            epoch_duration: SHORT_EPOCH_TIME,
+            mode,
+        }
+    );
+}
+
+#[test]
+fn set_flow_limit_config_fails_on_invalid_epoch_duration() {
+    let (_, client, _, token) = setup();
+
+    for epoch_duration in [0, MAX_EPOCH_TIME + 1] {
+        assert_contract_err!(
+            client.mock_all_auths().try_set_flow_limit_config(
+                &token.deployer,
+                &token.id,
+                &epoch_duration,
+                &FlowLimitMode::Fixed
+            ),
+            ContractError::InvalidFlowLimitEpoch
+        );
+    }
+}
+
+#[test]
+fn set_flow_limit_config_fails_if_not_flow_limiter() {
+    let (env, client, _, token) = setup();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_set_flow_limit_config(
+            &Address::generate(&env),
+            &token.id,
+            &SHORT_EPOCH_TIME,
+            &FlowLimitMode::Fixed
+        ),
+        ContractError::NotFlowLimiter
+    );
+}
+
+#[test]
+fn set_flow_limit_config_fails_without_caller_authorization() {
+    let (env, client, _, token) = setup();
+    let user = Address::generate(&env);
+
+    assert_auth_err!(
+        user,
+        client.set_flow_limit_config(
+            &token.deployer,
+            &token.id,
+            &SHORT_EPOCH_TIME,
+            &FlowLimitMode::Fixed
+        )
+    );
+}
+
+#[test]
+fn flow_limit_resets_after_configured_epoch() {
+    let (env, client, gateway, token) = setup();
+
+    client.mock_all_auths().set_flow_limit_config(
+        &token.deployer,
+        &token.id,
+        &SHORT_EPOCH_TIME,
+        &FlowLimitMode::Fixed,
+    );
+    env.ledger().set_timestamp(10 * SHORT_EPOCH_TIME);
+
+    let amount = dummy_flow_limit();
+
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount);
+
+    assert_eq!(client.flow_in_amount(&token.id), amount);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + SHORT_EPOCH_TIME);
+
+    assert_eq!(client.flow_in_amount(&token.id), 0);
+
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount);
+}
+
+#[test]
+fn fixed_epochs_allow_exceeding_flow_limit_across_epoch_boundary() {
+    let (env, client, gateway, token) = setup();
+
+    let amount = dummy_flow_limit();
+
+    env.ledger().set_timestamp(10 * EPOCH_TIME - 1);
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount);
+
+    env.ledger().set_timestamp(10 * EPOCH_TIME);
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount);
+
+    assert_eq!(client.flow_in_amount(&token.id), amount);
+}
+
+#[test]
+fn sliding_window_prevents_exceeding_flow_limit_across_epoch_boundary() {
+    let (env, client, gateway, token) = setup();
+
+    client.mock_all_auths().set_flow_limit_config(
+        &token.deployer,
+        &token.id,
+        &EPOCH_TIME,
+        &FlowLimitMode::SlidingWindow,
+    );
+
+    let amount = dummy_flow_limit();
+
+    env.ledger().set_timestamp(10 * EPOCH_TIME - 1);
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount);
+
+    env.ledger().set_timestamp(10 * EPOCH_TIME);
+    assert_eq!(client.flow_in_amount(&token.id), amount);
+
+    let msg = approve_its_transfer(&env, &client, &gateway, &token.id, 1);
+
+    assert_contract_err!(
+        client.try_execute(
+            &msg.source_chain,
+            &msg.message_id,
+            &msg.source_address,
+            &msg.payload
+        ),
+        ContractError::FlowLimitExceeded
+    );
+}
+
+#[test]
+fn sliding_window_interpolates_previous_epoch_flow() {
+    let (env, client, gateway, token) = setup();
+    let gas_token = setup_gas_token(&env, &client, &token.deployer);
+
+    client.mock_all_auths().set_flow_limit_config(
+        &token.deployer,
+        &token.id,
+        &SHORT_EPOCH_TIME,
+        &FlowLimitMode::SlidingWindow,
+    );
+
+    let amount = dummy_flow_limit();
+    let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    env.ledger().set_timestamp(10 * SHORT_EPOCH_TIME);
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount);
+    client.mock_all_auths().interchain_transfer(
+        &token.deployer,
+        &token.id,
+        &destination_chain,
+        &destination_address,
+        &(amount / 2),
+        &data,
+        &Some(gas_token),
+    );
+
+    env.ledger()
+        .set_timestamp(11 * SHORT_EPOCH_TIME + SHORT_EPOCH_TIME / 4);
+    assert_eq!(client.flow_in_amount(&token.id), amount * 3 / 4);
+    assert_eq!(client.flow_out_amount(&token.id), amount / 2 * 3 / 4);
+
+    execute_its_transfer(&env, &client, &gateway, &token.id, amount / 4);
+    assert_eq!(client.flow_in_amount(&token.id), amount);
+
+    env.ledger().set_timestamp(12 * SHORT_EPOCH_TIME);
+    assert_eq!(client.flow_in_amount(&token.id), amount / 4);
+    assert_eq!(client.flow_out_amount(&token.id), 0);
+}
@@ -0,0 +1,210 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN};
+use soroban_token_sdk::metadata::TokenMetadata;
+use stellar_axelar_std::{assert_auth, assert_auth_err, assert_contract_err, events};
+
+use super::utils::{setup_env, TokenMetadataExt};
+use crate::error::ContractError;
+use crate::event::{FlowLimiterAddedEvent, FlowLimiterRemovedEvent};
+use crate::testutils::setup_its_token;
+use crate::types::TokenManagerType;
+
+#[test]
+fn deploy_interchain_token_adds_caller_as_flow_limiter() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert!(client.is_flow_limiter(&token_id, &deployer));
+}
+
+#[test]
+fn deploy_interchain_token_adds_minter_as_flow_limiter() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let minter = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    let token_id = client.mock_all_auths().deploy_interchain_token(
+        &deployer,
+        &salt,
+        &TokenMetadata::new(&env, "Test", "TEST", 6),
+        &0,
+        &Some(minter.clone()),
+    );
+
+    assert!(client.is_flow_limiter(&token_id, &minter));
+    assert!(!client.is_flow_limiter(&token_id, &deployer));
+}
+
+#[test]
+fn register_custom_token_adds_deployer_as_flow_limiter() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let token_id = client.mock_all_auths().register_custom_token(
+        &deployer,
+        &BytesN::<32>::from_array(&env, &[1; 32]),
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+
+    assert!(client.is_flow_limiter(&token_id, &deployer));
+}
+
+#[test]
+fn flow_limiter_can_set_flow_limit() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let flow_limit = Some(100);
+
+    assert_auth!(
+        deployer,
+        client.set_flow_limit(&deployer, &token_id, &flow_limit)
+    );
+
+    assert_eq!(client.flow_limit(&token_id), flow_limit);
+}
+
+#[test]
+fn set_flow_limit_fails_if_not_flow_limiter() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_set_flow_limit(&Address::generate(&env), &token_id, &Some(100)),
+        ContractError::NotFlowLimiter
+    );
+}
+
+#[test]
+fn set_flow_limit_fails_without_authorization() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+    let flow_limit = Some(100);
+    let user = Address::generate(&env);
+
+    assert_auth_err!(
+        user,
+        client.set_flow_limit(&deployer, &token_id, &flow_limit)
+    );
+}
+
+#[test]
+fn add_flow_limiter_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let flow_limiter = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_auth!(
+        deployer,
+        client.add_flow_limiter(&deployer, &token_id, &flow_limiter)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<FlowLimiterAddedEvent>(
+        &env
+    ));
+
+    assert!(client.is_flow_limiter(&token_id, &flow_limiter));
+}
+
+#[test]
+fn operator_can_add_flow_limiter() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let flow_limiter = Address::generate(&env);
+    let operator = client.operator();
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_auth!(
+        operator,
+        client.add_flow_limiter(&operator, &token_id, &flow_limiter)
+    );
+
+    assert!(client.is_flow_limiter(&token_id, &flow_limiter));
+}
+
+#[test]
+fn add_flow_limiter_fails_if_already_set() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_add_flow_limiter(&deployer, &token_id, &deployer),
+        ContractError::FlowLimiterAlreadySet
+    );
+}
+
+#[test]
+fn add_flow_limiter_fails_if_not_flow_limiter() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let caller = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_contract_err!(
+        client
+            .mock_all_auths()
+            .try_add_flow_limiter(&caller, &token_id, &caller),
+        ContractError::NotFlowLimiter
+    );
+}
+
+#[test]
+fn add_flow_limiter_fails_with_unregistered_token() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_add_flow_limiter(
+            &client.operator(),
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &Address::generate(&env)
+        ),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn remove_flow_limiter_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_auth!(
+        deployer,
+        client.remove_flow_limiter(&deployer, &token_id, &deployer)
+    );
+    goldie::assert!(events::fmt_last_emitted_event::<FlowLimiterRemovedEvent>(
+        &env
+    ));
+
+    assert!(!client.is_flow_limiter(&token_id, &deployer));
+}
+
+#[test]
+fn remove_flow_limiter_fails_if_not_set() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let (token_id, _) = setup_its_token(&env, &client, &deployer, 100);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_remove_flow_limiter(
+            &client.operator(),
+            &token_id,
+            &Address::generate(&env)
+        ),
+        ContractError::FlowLimiterNotSet
+    );
+}
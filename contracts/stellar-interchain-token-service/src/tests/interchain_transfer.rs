@@ -1,12 +1,11 @@
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
 use soroban_sdk::{Address, Bytes, BytesN, Env, String};
-use stellar_axelar_gas_service::testutils::setup_gas_token;
 use stellar_axelar_std::traits::BytesExt;
 use stellar_axelar_std::types::Token;
 use stellar_axelar_std::{assert_contract_err, events};
 
-use super::utils::setup_env;
+use super::utils::{setup_env, setup_gas_token};
 use crate::error::ContractError;
 use crate::event::InterchainTransferSentEvent;
 use crate::testutils::setup_its_token;
@@ -26,7 +25,7 @@ fn setup_sender(
     amount: i128,
 ) -> (Address, Token, BytesN<32>) {
     let sender: Address = Address::generate(env);
-    let gas_token = setup_gas_token(env, &sender);
+    let gas_token = setup_gas_token(env, client, &sender);
     let (token_id, _) = setup_its_token(env, client, &sender, amount);
 
     (sender, gas_token, token_id)
@@ -93,7 +92,7 @@ fn interchain_transfer_canonical_token_send_succeeds() {
 
     let amount = 1000;
     let sender: Address = Address::generate(&env);
-    let gas_token = setup_gas_token(&env, &sender);
+    let gas_token = setup_gas_token(&env, &client, &sender);
     let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
 
     let token_address = env
@@ -153,7 +152,7 @@ fn interchain_transfer_send_fails_when_paused() {
             &Bytes::from_hex(&env, ""),
             &1,
             &Some(Bytes::from_hex(&env, "")),
-            &Some(setup_gas_token(&env, &Address::generate(&env)))
+            &Some(setup_gas_token(&env, &client, &Address::generate(&env)))
         ),
         ContractError::ContractPaused
     );
@@ -0,0 +1,372 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, String};
+use stellar_axelar_gateway::testutils::{approve_gateway_messages, TestSignerSet};
+use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_gateway::AxelarGatewayClient;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::types::Token;
+use stellar_axelar_std::{assert_auth, assert_contract_err, events};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::{LinkTokenStartedEvent, TokenManagerDeployedEvent};
+use crate::types::{HubMessage, LinkToken, Message, TokenManagerType};
+use crate::InterchainTokenServiceClient;
+
+fn register_dummy_custom_token(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+) -> (Address, BytesN<32>) {
+    let deployer = Address::generate(env);
+    let salt = BytesN::<32>::from_array(env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+
+    (deployer, salt)
+}
+
+/// Approves a `LinkToken` message from the ITS Hub, returning the arguments to execute it with.
+fn approve_link_token_message(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    link_token: LinkToken,
+) -> (String, String, String, Bytes) {
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+    let original_source_chain = String::from_str(env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let msg = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::LinkToken(link_token),
+    };
+    let message_id = String::from_str(env, "test");
+    let payload = msg.abi_encode(env).unwrap();
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+
+    let messages = vec![
+        env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash,
+        },
+    ];
+
+    approve_gateway_messages(env, gateway_client, signers, messages);
+
+    (source_chain, message_id, source_address, payload)
+}
+
+fn dummy_link_params(env: &Env) -> (String, Bytes, Option<Bytes>) {
+    let destination_chain = String::from_str(env, "ethereum");
+    let destination_token_address =
+        Bytes::from_hex(env, "4F4495243837681061C4743b74B3eEdf548D56A5");
+    let link_params = Some(Bytes::from_hex(env, "abcd"));
+
+    (destination_chain, destination_token_address, link_params)
+}
+
+#[test]
+fn link_token_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let (deployer, salt) = register_dummy_custom_token(&env, &client);
+    let (destination_chain, destination_token_address, link_params) = dummy_link_params(&env);
+
+    let gas_token: Option<Token> = None;
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    let token_id = assert_auth!(
+        deployer,
+        client.link_token(
+            &deployer,
+            &salt,
+            &destination_chain,
+            &destination_token_address,
+            &TokenManagerType::MintBurn,
+            &link_params,
+            &gas_token
+        )
+    );
+    goldie::assert!(events::fmt_emitted_event_at_idx::<LinkTokenStartedEvent>(
+        &env, -2
+    ));
+
+    assert_eq!(token_id, client.linked_token_id(&deployer, &salt));
+}
+
+#[test]
+fn link_token_fails_if_not_registered() {
+    let (env, client, _, _, _) = setup_env();
+    let (destination_chain, destination_token_address, link_params) = dummy_link_params(&env);
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &Address::generate(&env),
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &destination_chain,
+            &destination_token_address,
+            &TokenManagerType::LockUnlock,
+            &link_params,
+            &None
+        ),
+        ContractError::InvalidTokenId
+    );
+}
+
+#[test]
+fn link_token_fails_with_native_interchain_token_type() {
+    let (env, client, _, _, _) = setup_env();
+    let (deployer, salt) = register_dummy_custom_token(&env, &client);
+    let (destination_chain, destination_token_address, link_params) = dummy_link_params(&env);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &salt,
+            &destination_chain,
+            &destination_token_address,
+            &TokenManagerType::NativeInterchainToken,
+            &link_params,
+            &None
+        ),
+        ContractError::InvalidTokenManagerType
+    );
+}
+
+#[test]
+fn link_token_fails_with_empty_destination_token_address() {
+    let (env, client, _, _, _) = setup_env();
+    let (deployer, salt) = register_dummy_custom_token(&env, &client);
+    let (destination_chain, _, link_params) = dummy_link_params(&env);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &salt,
+            &destination_chain,
+            &Bytes::new(&env),
+            &TokenManagerType::LockUnlock,
+            &link_params,
+            &None
+        ),
+        ContractError::InvalidDestinationAddress
+    );
+}
+
+#[test]
+fn link_token_fails_with_current_chain() {
+    let (env, client, _, _, _) = setup_env();
+    let (deployer, salt) = register_dummy_custom_token(&env, &client);
+    let (_, destination_token_address, link_params) = dummy_link_params(&env);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &salt,
+            &client.chain_name(),
+            &destination_token_address,
+            &TokenManagerType::LockUnlock,
+            &link_params,
+            &None
+        ),
+        ContractError::InvalidDestinationChain
+    );
+}
+
+#[test]
+fn link_token_fails_with_untrusted_chain() {
+    let (env, client, _, _, _) = setup_env();
+    let (deployer, salt) = register_dummy_custom_token(&env, &client);
+    let (destination_chain, destination_token_address, link_params) = dummy_link_params(&env);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_link_token(
+            &deployer,
+            &salt,
+            &destination_chain,
+            &destination_token_address,
+            &TokenManagerType::LockUnlock,
+            &link_params,
+            &None
+        ),
+        ContractError::UntrustedChain
+    );
+}
+
+#[test]
+fn link_token_message_execute_succeeds() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+    let original_source_chain = String::from_str(&env, "ethereum");
+    let token_id = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let msg = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::LinkToken(LinkToken {
+            token_id: token_id.clone(),
+            token_manager_type: TokenManagerType::LockUnlock,
+            source_token_address: Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            destination_token_address: token_address.to_string_bytes(),
+            params: None,
+        }),
+    };
+    let message_id = String::from_str(&env, "test");
+    let payload = msg.abi_encode(&env).unwrap();
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+
+    let messages = vec![
+        &env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash,
+        },
+    ];
+
+    approve_gateway_messages(&env, &gateway_client, signers, messages);
+
+    client.execute(&source_chain, &message_id, &source_address, &payload);
+
+    goldie::assert!(events::fmt_last_emitted_event::<TokenManagerDeployedEvent>(
+        &env
+    ));
+
+    assert_eq!(client.registered_token_address(&token_id), token_address);
+    assert_eq!(
+        client.token_manager_type(&token_id),
+        TokenManagerType::LockUnlock
+    );
+}
+
+#[test]
+fn link_token_message_execute_fails_with_native_interchain_token_type() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+    let original_source_chain = String::from_str(&env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let msg = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::LinkToken(LinkToken {
+            token_id: BytesN::<32>::from_array(&env, &[1; 32]),
+            token_manager_type: TokenManagerType::NativeInterchainToken,
+            source_token_address: Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            destination_token_address: Address::generate(&env).to_string_bytes(),
+            params: None,
+        }),
+    };
+    let message_id = String::from_str(&env, "test");
+    let payload = msg.abi_encode(&env).unwrap();
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+
+    let messages = vec![
+        &env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash,
+        },
+    ];
+
+    approve_gateway_messages(&env, &gateway_client, signers, messages);
+
+    assert_contract_err!(
+        client.try_execute(&source_chain, &message_id, &source_address, &payload),
+        ContractError::InvalidTokenManagerType
+    );
+}
+
+#[test]
+fn link_token_message_execute_fails_with_invalid_token_address() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let (source_chain, message_id, source_address, payload) = approve_link_token_message(
+        &env,
+        &client,
+        &gateway_client,
+        signers,
+        LinkToken {
+            token_id: BytesN::<32>::from_array(&env, &[1; 32]),
+            token_manager_type: TokenManagerType::LockUnlock,
+            source_token_address: Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            destination_token_address: Address::generate(&env).to_string_bytes(),
+            params: None,
+        },
+    );
+
+    assert_contract_err!(
+        client.try_execute(&source_chain, &message_id, &source_address, &payload),
+        ContractError::InvalidTokenAddress
+    );
+}
+
+#[test]
+fn link_token_message_execute_fails_with_link_params() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let (_, _, link_params) = dummy_link_params(&env);
+
+    let (source_chain, message_id, source_address, payload) = approve_link_token_message(
+        &env,
+        &client,
+        &gateway_client,
+        signers,
+        LinkToken {
+            token_id: BytesN::<32>::from_array(&env, &[1; 32]),
+            token_manager_type: TokenManagerType::LockUnlock,
+            source_token_address: Bytes::from_hex(&env, "4F4495243837681061C4743b74B3eEdf548D56A5"),
+            destination_token_address: token_address.to_string_bytes(),
+            params: link_params,
+        },
+    );
+
+    assert_contract_err!(
+        client.try_execute(&source_chain, &message_id, &source_address, &payload),
+        ContractError::InvalidData
+    );
+}
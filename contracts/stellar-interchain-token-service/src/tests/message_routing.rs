@@ -1,11 +1,10 @@
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, Bytes, String};
-use stellar_axelar_gas_service::testutils::setup_gas_token;
 use stellar_axelar_std::address::AddressExt;
 use stellar_axelar_std::assert_contract_err;
 use stellar_axelar_std::traits::BytesExt;
 
-use super::utils::setup_env;
+use super::utils::{setup_env, setup_gas_token};
 use crate::error::ContractError;
 use crate::testutils::setup_its_token;
 
@@ -16,7 +15,7 @@ fn send_directly_to_hub_chain_fails() {
     let sender: Address = Address::generate(&env);
     let amount = 1;
     let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
-    let gas_token = setup_gas_token(&env, &sender);
+    let gas_token = setup_gas_token(&env, &client, &sender);
 
     assert_contract_err!(
         client.mock_all_auths().try_interchain_transfer(
@@ -42,7 +41,7 @@ fn send_to_untrusted_chain_fails() {
     let sender: Address = Address::generate(&env);
     let amount = 1;
     let (token_id, _) = setup_its_token(&env, &client, &sender, amount);
-    let gas_token = setup_gas_token(&env, &sender);
+    let gas_token = setup_gas_token(&env, &client, &sender);
 
     assert_contract_err!(
         client.mock_all_auths().try_interchain_transfer(
@@ -5,10 +5,15 @@ mod deployer;
 mod executable;
 mod execute;
 mod flow_limit;
+mod flow_limiter;
 mod interchain_transfer;
+mod link_token;
 mod message_routing;
 mod pause;
 mod register_canonical_token;
+mod register_custom_token;
+mod register_token_metadata;
 mod token_id;
+mod token_manager_type;
 mod trusted_chain;
 mod utils;
@@ -0,0 +1,114 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN};
+use stellar_axelar_std::{assert_auth, assert_contract_err, events};
+
+use super::utils::setup_env;
+use crate::error::ContractError;
+use crate::event::TokenManagerDeployedEvent;
+use crate::types::TokenManagerType;
+
+#[test]
+fn register_custom_token_succeeds() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_manager_type = TokenManagerType::MintBurn;
+    let expected_id = client.linked_token_id(&deployer, &salt);
+
+    let token_id = assert_auth!(
+        deployer,
+        client.register_custom_token(&deployer, &salt, &token_address, &token_manager_type)
+    );
+    assert_eq!(token_id, expected_id);
+
+    goldie::assert!(events::fmt_last_emitted_event::<TokenManagerDeployedEvent>(
+        &env
+    ));
+
+    assert_eq!(client.registered_token_address(&token_id), token_address);
+    assert_eq!(client.token_manager_type(&token_id), token_manager_type);
+    assert_eq!(
+        client.deployed_token_manager(&token_id),
+        client.token_manager_address(&token_id)
+    );
+}
+
+#[test]
+fn register_custom_token_fails_with_native_interchain_token_type() {
+    let (env, client, _, _, _) = setup_env();
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &Address::generate(&env),
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &token_address,
+            &TokenManagerType::NativeInterchainToken
+        ),
+        ContractError::InvalidTokenManagerType
+    );
+}
+
+#[test]
+fn register_custom_token_fails_with_invalid_token_address() {
+    let (env, client, _, _, _) = setup_env();
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &Address::generate(&env),
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &Address::generate(&env),
+            &TokenManagerType::LockUnlock
+        ),
+        ContractError::InvalidTokenAddress
+    );
+}
+
+#[test]
+fn register_custom_token_fails_if_already_registered() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::generate(&env);
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        &token_address,
+        &TokenManagerType::LockUnlock,
+    );
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_custom_token(
+            &deployer,
+            &salt,
+            &token_address,
+            &TokenManagerType::LockUnlock
+        ),
+        ContractError::TokenAlreadyRegistered
+    );
+}
+
+#[test]
+fn register_custom_token_fails_when_paused() {
+    let (env, client, _, _, _) = setup_env();
+
+    client.mock_all_auths().pause();
+
+    assert_contract_err!(
+        client.try_register_custom_token(
+            &Address::generate(&env),
+            &BytesN::<32>::from_array(&env, &[1; 32]),
+            &Address::generate(&env),
+            &TokenManagerType::LockUnlock
+        ),
+        ContractError::ContractPaused
+    );
+}
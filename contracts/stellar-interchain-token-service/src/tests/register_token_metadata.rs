@@ -0,0 +1,105 @@
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{token, Address, IntoVal, Symbol};
+use stellar_axelar_gas_service::types::GasMetadata;
+use stellar_axelar_gateway::testutils::last_contract_call_message_id;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::{assert_contract_err, auth_invocation, events};
+
+use super::utils::{setup_env, setup_gas_token};
+use crate::error::ContractError;
+use crate::event::TokenMetadataRegisteredEvent;
+use crate::types::{HubMessage, RegisterTokenMetadata};
+
+#[test]
+fn register_token_metadata_succeeds() {
+    let (env, client, gateway_client, gas_service, _) = setup_env();
+    let spender = Address::generate(&env);
+    let gas_token = setup_gas_token(&env, &client, &spender);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let payload = HubMessage::RegisterTokenMetadata(RegisterTokenMetadata {
+        token_address: token_address.to_string_bytes(),
+        decimals: token::Client::new(&env, &token_address).decimals() as u8,
+    })
+    .abi_encode(&env)
+    .unwrap();
+    let its_hub_chain = client.its_hub_chain_name();
+    let its_hub_address = client.its_hub_address();
+
+    client.mock_all_auths().register_token_metadata(
+        &token_address,
+        &spender,
+        &Some(gas_token.clone()),
+    );
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        TokenMetadataRegisteredEvent,
+    >(&env, -4));
+
+    let transfer_auth = auth_invocation!(
+        &env,
+        spender,
+        gas_token.transfer(
+            spender.clone(),
+            gas_service.address.clone(),
+            gas_token.amount
+        )
+    );
+
+    let gas_service_auth = auth_invocation!(
+        &env,
+        spender,
+        gas_service.pay_gas(
+            client.address.clone(),
+            last_contract_call_message_id(&env, &gateway_client),
+            its_hub_chain,
+            its_hub_address,
+            payload,
+            spender.clone(),
+            gas_token.clone(),
+            None::<GasMetadata>
+        ),
+        transfer_auth
+    );
+
+    let register_token_metadata_auth = auth_invocation!(
+        &env,
+        spender,
+        client.register_token_metadata(token_address, spender, Some(gas_token)),
+        gas_service_auth
+    );
+
+    assert_eq!(env.auths(), register_token_metadata_auth);
+}
+
+#[test]
+fn register_token_metadata_fails_with_invalid_token_address() {
+    let (env, client, _, _, _) = setup_env();
+    let spender = Address::generate(&env);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_register_token_metadata(
+            &Address::generate(&env),
+            &spender,
+            &None
+        ),
+        ContractError::InvalidTokenAddress
+    );
+}
+
+#[test]
+fn register_token_metadata_fails_when_paused() {
+    let (env, client, _, _, _) = setup_env();
+
+    client.mock_all_auths().pause();
+
+    assert_contract_err!(
+        client.try_register_token_metadata(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &None
+        ),
+        ContractError::ContractPaused
+    );
+}
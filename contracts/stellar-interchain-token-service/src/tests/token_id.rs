@@ -32,3 +32,18 @@ fn interchain_token_id_is_unchanged() {
         client.interchain_token_id(&deployer, &salt).to_array()
     ));
 }
+
+// NOTE: This MUST NOT change after the initial deployment to avoid breaking existing logic
+#[test]
+fn linked_token_id_is_unchanged() {
+    let (env, client, _, _, _) = setup_env();
+    let deployer = Address::from_str(
+        &env,
+        "GDUITDF2LI3R5HM4KYRLLNRLEWKYBFVZVOEB6HSL7EOW2KO2LD6V4GPM",
+    );
+    let salt = BytesN::<32>::from_array(&env, &[1; 32]);
+
+    goldie::assert!(hex::encode(
+        client.linked_token_id(&deployer, &salt).to_array()
+    ));
+}
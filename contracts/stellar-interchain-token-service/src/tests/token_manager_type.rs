@@ -0,0 +1,342 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env, String};
+use stellar_axelar_gateway::testutils::{approve_gateway_messages, TestSignerSet};
+use stellar_axelar_gateway::types::Message as GatewayMessage;
+use stellar_axelar_gateway::AxelarGatewayClient;
+use stellar_axelar_std::address::AddressExt;
+use stellar_axelar_std::traits::BytesExt;
+use stellar_axelar_std::types::Token;
+use stellar_axelar_std::{assert_contract_err, events};
+
+use super::utils::{setup_custom_token, setup_env};
+use crate::error::ContractError;
+use crate::event::InterchainTransferSentEvent;
+use crate::types::{HubMessage, InterchainTransfer, Message, TokenManagerType};
+use crate::InterchainTokenServiceClient;
+
+const FEE_BPS: i128 = 100;
+
+/// A minimal token that burns a 1% fee, rounded up, on every transfer.
+#[contract]
+pub struct FeeOnTransferToken;
+
+#[contractimpl]
+impl FeeOnTransferToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn name(env: Env) -> String {
+        String::from_str(&env, "Fee On Transfer")
+    }
+
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, "FOT")
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+
+        let fee = (amount * FEE_BPS + 9_999) / 10_000;
+        let from_balance = Self::balance(env.clone(), from.clone());
+        assert!(from_balance >= amount, "balance is not sufficient to spend");
+
+        env.storage()
+            .persistent()
+            .set(&from, &(from_balance - amount));
+        Self::mint(env, to, amount - fee);
+    }
+}
+
+fn dummy_transfer_params(env: &Env) -> (String, Bytes, Option<Bytes>) {
+    let destination_chain = String::from_str(env, "ethereum");
+    let destination_address = Bytes::from_hex(env, "4F4495243837681061C4743b74B3eEdf548D56A5");
+    let data = None;
+
+    (destination_chain, destination_address, data)
+}
+
+fn setup_stellar_asset(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+fn interchain_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    sender: &Address,
+    token_id: &BytesN<32>,
+    amount: i128,
+) {
+    let (destination_chain, destination_address, data) = dummy_transfer_params(env);
+    let gas_token: Option<Token> = None;
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    client.mock_all_auths().interchain_transfer(
+        sender,
+        token_id,
+        &destination_chain,
+        &destination_address,
+        &amount,
+        &data,
+        &gas_token,
+    );
+}
+
+fn execute_transfer(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    gateway_client: &AxelarGatewayClient,
+    signers: TestSignerSet,
+    token_id: &BytesN<32>,
+    amount: i128,
+) -> Address {
+    let recipient = Address::generate(env);
+    let source_chain = client.its_hub_chain_name();
+    let source_address = client.its_hub_address();
+    let original_source_chain = String::from_str(env, "ethereum");
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&original_source_chain);
+
+    let msg = HubMessage::ReceiveFromHub {
+        source_chain: original_source_chain,
+        message: Message::InterchainTransfer(InterchainTransfer {
+            token_id: token_id.clone(),
+            source_address: Address::generate(env).to_string_bytes(),
+            destination_address: recipient.to_string_bytes(),
+            amount,
+            data: None,
+        }),
+    };
+    let message_id = String::from_str(env, "test");
+    let payload = msg.abi_encode(env).unwrap();
+    let payload_hash: BytesN<32> = env.crypto().keccak256(&payload).into();
+
+    let messages = vec![
+        env,
+        GatewayMessage {
+            source_chain: source_chain.clone(),
+            message_id: message_id.clone(),
+            source_address: source_address.clone(),
+            contract_address: client.address.clone(),
+            payload_hash,
+        },
+    ];
+
+    approve_gateway_messages(env, gateway_client, signers, messages);
+
+    client.execute(&source_chain, &message_id, &source_address, &payload);
+
+    recipient
+}
+
+#[test]
+fn mint_burn_interchain_transfer_burns_tokens() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let token_address = setup_stellar_asset(&env);
+    let (token_id, token_manager) =
+        setup_custom_token(&env, &client, &token_address, TokenManagerType::MintBurn);
+    let token = StellarAssetClient::new(&env, &token_address);
+    token.mock_all_auths().set_admin(&token_manager);
+    token.mock_all_auths().mint(&sender, &amount);
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+
+    let token = TokenClient::new(&env, &token_address);
+    assert_eq!(token.balance(&sender), 0);
+    assert_eq!(token.balance(&token_manager), 0);
+}
+
+#[test]
+fn mint_burn_execute_mints_tokens() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let amount = 1000;
+    let token_address = setup_stellar_asset(&env);
+    let (token_id, token_manager) =
+        setup_custom_token(&env, &client, &token_address, TokenManagerType::MintBurn);
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .set_admin(&token_manager);
+
+    let recipient = execute_transfer(&env, &client, &gateway_client, signers, &token_id, amount);
+
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&recipient),
+        amount
+    );
+}
+
+#[test]
+fn mint_burn_from_interchain_transfer_burns_from_allowance() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let token_address = setup_stellar_asset(&env);
+    let (token_id, token_manager) = setup_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::MintBurnFrom,
+    );
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&sender, &amount);
+
+    let token = TokenClient::new(&env, &token_address);
+    token
+        .mock_all_auths()
+        .approve(&sender, &token_manager, &amount, &env.ledger().sequence());
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+
+    assert_eq!(token.balance(&sender), 0);
+    assert_eq!(token.allowance(&sender, &token_manager), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")] // "allowance is not sufficient to spend"
+fn mint_burn_from_interchain_transfer_fails_without_allowance() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let token_address = setup_stellar_asset(&env);
+    let (token_id, _) = setup_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::MintBurnFrom,
+    );
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .mint(&sender, &amount);
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+}
+
+#[test]
+fn mint_burn_from_execute_mints_tokens() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let amount = 1000;
+    let token_address = setup_stellar_asset(&env);
+    let (token_id, token_manager) = setup_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::MintBurnFrom,
+    );
+    StellarAssetClient::new(&env, &token_address)
+        .mock_all_auths()
+        .set_admin(&token_manager);
+
+    let recipient = execute_transfer(&env, &client, &gateway_client, signers, &token_id, amount);
+
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&recipient),
+        amount
+    );
+}
+
+#[test]
+fn lock_unlock_fee_interchain_transfer_sends_received_amount() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1000;
+    let sender = Address::generate(&env);
+    let token_address = env.register(FeeOnTransferToken, ());
+    let (token_id, token_manager) = setup_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::LockUnlockFee,
+    );
+    let token = FeeOnTransferTokenClient::new(&env, &token_address);
+    token.mint(&sender, &amount);
+
+    interchain_transfer(&env, &client, &sender, &token_id, amount);
+
+    goldie::assert!(events::fmt_emitted_event_at_idx::<
+        InterchainTransferSentEvent,
+    >(&env, -2));
+
+    assert_eq!(token.balance(&sender), 0);
+    assert_eq!(token.balance(&token_manager), 990);
+}
+
+#[test]
+fn lock_unlock_fee_interchain_transfer_fails_if_nothing_received() {
+    let (env, client, _, _, _) = setup_env();
+
+    let amount = 1;
+    let sender = Address::generate(&env);
+    let token_address = env.register(FeeOnTransferToken, ());
+    let (token_id, _) = setup_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::LockUnlockFee,
+    );
+    FeeOnTransferTokenClient::new(&env, &token_address).mint(&sender, &amount);
+
+    let (destination_chain, destination_address, data) = dummy_transfer_params(&env);
+    let gas_token: Option<Token> = None;
+
+    client
+        .mock_all_auths()
+        .set_trusted_chain(&destination_chain);
+
+    assert_contract_err!(
+        client.mock_all_auths().try_interchain_transfer(
+            &sender,
+            &token_id,
+            &destination_chain,
+            &destination_address,
+            &amount,
+            &data,
+            &gas_token,
+        ),
+        ContractError::InvalidAmount
+    );
+}
+
+#[test]
+fn lock_unlock_fee_execute_unlocks_tokens() {
+    let (env, client, gateway_client, _, signers) = setup_env();
+
+    let amount = 1000;
+    let token_address = env.register(FeeOnTransferToken, ());
+    let (token_id, token_manager) = setup_custom_token(
+        &env,
+        &client,
+        &token_address,
+        TokenManagerType::LockUnlockFee,
+    );
+    let token = FeeOnTransferTokenClient::new(&env, &token_address);
+    token.mint(&token_manager, &amount);
+
+    let recipient = execute_transfer(&env, &client, &gateway_client, signers, &token_id, amount);
+
+    assert_eq!(token.balance(&token_manager), 0);
+    assert_eq!(token.balance(&recipient), 990);
+}
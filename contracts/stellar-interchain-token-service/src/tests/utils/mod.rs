@@ -1,11 +1,14 @@
-use soroban_sdk::{Env, IntoVal};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, IntoVal};
 use soroban_token_sdk::metadata::TokenMetadata;
-use stellar_axelar_gas_service::testutils::setup_gas_service;
+use stellar_axelar_gas_service::testutils::{self as gas_service_testutils, setup_gas_service};
 use stellar_axelar_gas_service::AxelarGasServiceClient;
 use stellar_axelar_gateway::testutils::{setup_gateway, TestSignerSet};
 use stellar_axelar_gateway::AxelarGatewayClient;
+use stellar_axelar_std::types::Token;
 
 use crate::testutils::setup_its;
+use crate::types::TokenManagerType;
 use crate::InterchainTokenServiceClient;
 
 pub fn setup_env<'a>() -> (
@@ -25,6 +28,38 @@ pub fn setup_env<'a>() -> (
     (env, client, gateway_client, gas_service_client, signers)
 }
 
+pub fn setup_gas_token(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    sender: &Address,
+) -> Token {
+    let gas_service = AxelarGasServiceClient::new(env, &client.gas_service());
+
+    gas_service_testutils::setup_gas_token(env, &gas_service, sender)
+}
+
+/// Registers an existing token with ITS under a token manager of the given type,
+/// returning the token ID and the deployed token manager.
+pub fn setup_custom_token(
+    env: &Env,
+    client: &InterchainTokenServiceClient,
+    token_address: &Address,
+    token_manager_type: TokenManagerType,
+) -> (BytesN<32>, Address) {
+    let deployer = Address::generate(env);
+    let salt = BytesN::<32>::from_array(env, &[2; 32]);
+
+    let token_id = client.mock_all_auths().register_custom_token(
+        &deployer,
+        &salt,
+        token_address,
+        &token_manager_type,
+    );
+    let token_manager = client.deployed_token_manager(&token_id);
+
+    (token_id, token_manager)
+}
+
 pub trait TokenMetadataExt {
     fn new(env: &Env, name: &str, symbol: &str, decimal: u32) -> Self;
 }
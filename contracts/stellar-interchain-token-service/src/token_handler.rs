@@ -1,5 +1,6 @@
 use soroban_sdk::token::TokenClient;
 use soroban_sdk::{Address, Env};
+use stellar_axelar_std::ensure;
 use stellar_token_manager::TokenManagerClient;
 
 use crate::error::ContractError;
@@ -7,6 +8,10 @@ use crate::storage::TokenIdConfigValue;
 use crate::token_manager::TokenManagerClientExt;
 use crate::types::TokenManagerType;
 
+/// Takes `amount` of tokens from `sender` and returns the amount actually taken.
+///
+/// The returned amount only differs from `amount` for [`TokenManagerType::LockUnlockFee`] tokens,
+/// where the fee on transfer is deducted from the amount received by the token manager.
 pub fn take_token(
     env: &Env,
     sender: &Address,
@@ -16,15 +21,35 @@ pub fn take_token(
         token_manager_type,
     }: TokenIdConfigValue,
     amount: i128,
-) -> Result<(), ContractError> {
+) -> Result<i128, ContractError> {
     let token = TokenClient::new(env, &token_address);
 
     match token_manager_type {
-        TokenManagerType::NativeInterchainToken => token.burn(sender, &amount),
+        TokenManagerType::NativeInterchainToken | TokenManagerType::MintBurn => {
+            token.burn(sender, &amount)
+        }
+        TokenManagerType::MintBurnFrom => TokenManagerClient::new(env, &token_manager).burn_from(
+            env,
+            &token_address,
+            sender,
+            amount,
+        ),
         TokenManagerType::LockUnlock => token.transfer(sender, &token_manager, &amount),
+        TokenManagerType::LockUnlockFee => {
+            let balance_before = token.balance(&token_manager);
+            token.transfer(sender, &token_manager, &amount);
+            let received = token
+                .balance(&token_manager)
+                .checked_sub(balance_before)
+                .ok_or(ContractError::InvalidAmount)?;
+
+            ensure!(received > 0, ContractError::InvalidAmount);
+
+            return Ok(received);
+        }
     }
 
-    Ok(())
+    Ok(amount)
 }
 
 pub fn give_token(
@@ -43,7 +68,10 @@ pub fn give_token(
         TokenManagerType::NativeInterchainToken => {
             token_manager.mint(env, &token_address, recipient, amount)
         }
-        TokenManagerType::LockUnlock => {
+        TokenManagerType::MintBurn | TokenManagerType::MintBurnFrom => {
+            token_manager.admin_mint(env, &token_address, recipient, amount)
+        }
+        TokenManagerType::LockUnlock | TokenManagerType::LockUnlockFee => {
             token_manager.transfer(env, &token_address, recipient, amount)
         }
     }
@@ -7,6 +7,12 @@ pub trait TokenManagerClientExt {
 
     /// Mint `amount` of tokens to `recipient`.
     fn mint(&self, env: &Env, token_address: &Address, recipient: &Address, amount: i128);
+
+    /// Mint `amount` of tokens to `recipient` using the token manager's admin role on the token.
+    fn admin_mint(&self, env: &Env, token_address: &Address, recipient: &Address, amount: i128);
+
+    /// Burn `amount` of tokens from `from`, using the allowance granted to the token manager.
+    fn burn_from(&self, env: &Env, token_address: &Address, from: &Address, amount: i128);
 }
 
 impl TokenManagerClientExt for TokenManagerClient<'_> {
@@ -35,4 +41,25 @@ impl TokenManagerClientExt for TokenManagerClient<'_> {
             ],
         );
     }
+
+    fn admin_mint(&self, env: &Env, token_address: &Address, recipient: &Address, amount: i128) {
+        let _: Val = self.execute(
+            token_address,
+            &Symbol::new(env, "mint"),
+            &vec![env, recipient.to_val(), amount.into_val(env)],
+        );
+    }
+
+    fn burn_from(&self, env: &Env, token_address: &Address, from: &Address, amount: i128) {
+        let _: Val = self.execute(
+            token_address,
+            &Symbol::new(env, "burn_from"),
+            &vec![
+                env,
+                self.address.to_val(),
+                from.to_val(),
+                amount.into_val(env),
+            ],
+        );
+    }
 }
@@ -4,6 +4,7 @@ use soroban_sdk::{contracttype, Bytes, BytesN, String};
 pub enum Message {
     InterchainTransfer(InterchainTransfer),
     DeployInterchainToken(DeployInterchainToken),
+    LinkToken(LinkToken),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,6 +25,21 @@ pub struct DeployInterchainToken {
     pub minter: Option<Bytes>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkToken {
+    pub token_id: BytesN<32>,
+    pub token_manager_type: TokenManagerType,
+    pub source_token_address: Bytes,
+    pub destination_token_address: Bytes,
+    pub params: Option<Bytes>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisterTokenMetadata {
+    pub token_address: Bytes,
+    pub decimals: u8,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HubMessage {
     SendToHub {
@@ -34,19 +50,47 @@ pub enum HubMessage {
         source_chain: String,
         message: Message,
     },
+    /// Registers the metadata of a local token with the ITS Hub, so that it can be linked to tokens on other chains.
+    RegisterTokenMetadata(RegisterTokenMetadata),
 }
 
 /// The type of token manager used for the tokenId.
 ///
-/// Only the variants supported by Stellar ITS are defined here.
 /// The variant values need to match the [ITS spec](https://github.com/axelarnetwork/interchain-token-service/blob/v2.0.0/contracts/interfaces/ITokenManagerType.sol#L9).
 #[contracttype]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u32)]
 pub enum TokenManagerType {
+    /// The token is an interchain token deployed by ITS, and is burned/minted through the token manager's minter role.
     NativeInterchainToken = 0,
-    // MintBurnFrom = 1,
+    /// The token is burned out of the sender's allowance to the token manager, and minted by the token manager as the token admin.
+    MintBurnFrom = 1,
+    /// The token is locked in and unlocked from the token manager.
     LockUnlock = 2,
-    // LockUnlockFee = 3,
-    // MintBurn = 4,
+    /// Same as [`TokenManagerType::LockUnlock`], but for tokens that take a fee on transfer.
+    /// The amount actually received by the token manager is used for the transfer.
+    LockUnlockFee = 3,
+    /// The token is burned from the sender, and minted by the token manager as the token admin.
+    MintBurn = 4,
+}
+
+/// How the flow of a token is accounted for within its flow limit window.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum FlowLimitMode {
+    /// Flow is tracked in fixed epochs and resets at the start of every epoch.
+    Fixed = 0,
+    /// Flow of the previous epoch is carried over, weighted by how much of the current epoch is left.
+    /// This prevents moving up to twice the flow limit around an epoch boundary.
+    SlidingWindow = 1,
+}
+
+/// The flow limit window configuration of a token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlowLimitConfig {
+    /// The length of an epoch in seconds.
+    pub epoch_duration: u64,
+    pub mode: FlowLimitMode,
 }
@@ -2,23 +2,105 @@ use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::quote;
 
 pub fn axelar_executable(name: &Ident) -> TokenStream2 {
-    let error_alias = Ident::new(
-        &format!("__{}AxelarExecutableInterfaceError", name),
-        name.span(),
+    let error_alias = error_alias(name);
+
+    let executable_interface = axelar_executable_interface(
+        name,
+        &error_alias,
+        quote! {
+            <Self as stellar_axelar_gateway::executable::CustomAxelarExecutable>::__execute(env, source_chain, message_id, source_address, payload)
+        },
+    );
+
+    quote! {
+        use stellar_axelar_gateway::executable::AxelarExecutableInterface as _;
+
+        #executable_interface
+    }
+}
+
+pub fn axelar_express_executable(name: &Ident) -> TokenStream2 {
+    let error_alias = error_alias(name);
+
+    let executable_interface = axelar_executable_interface(
+        name,
+        &error_alias,
+        quote! {
+            match stellar_axelar_gateway::executable::fulfill_express_execution(env, &source_chain, &message_id, &source_address, &payload) {
+                Some(express_executor) => <Self as stellar_axelar_gateway::executable::CustomAxelarExpressExecutable>::__reimburse_express_executor(
+                    env,
+                    express_executor,
+                    source_chain,
+                    message_id,
+                    source_address,
+                    payload,
+                ),
+                None => <Self as stellar_axelar_gateway::executable::CustomAxelarExecutable>::__execute(env, source_chain, message_id, source_address, payload),
+            }
+        },
     );
 
     quote! {
         use stellar_axelar_gateway::executable::AxelarExecutableInterface as _;
+        use stellar_axelar_gateway::executable::AxelarExpressExecutableInterface as _;
+
+        #executable_interface
+
+        #[contractimpl]
+        impl stellar_axelar_gateway::executable::AxelarExpressExecutableInterface for #name {
+            fn express_execute(
+                env: &Env,
+                express_executor: Address,
+                source_chain: String,
+                message_id: String,
+                source_address: String,
+                payload: Bytes,
+            ) -> Result<(), #error_alias> {
+                stellar_axelar_gateway::executable::set_express_executor::<Self>(env, &express_executor, &source_chain, &message_id, &source_address, &payload).map_err(|err| match err {
+                    stellar_axelar_gateway::executable::ExpressValidationError::AlreadyApproved => #error_alias::AlreadyApproved,
+                    stellar_axelar_gateway::executable::ExpressValidationError::AlreadyExpressExecuted => #error_alias::AlreadyExpressExecuted,
+                })?;
+
+                <Self as stellar_axelar_gateway::executable::CustomAxelarExecutable>::__execute(env, source_chain, message_id, source_address, payload)
+            }
 
+            fn express_executor(
+                env: &Env,
+                source_chain: String,
+                message_id: String,
+                source_address: String,
+                payload_hash: soroban_sdk::BytesN<32>,
+            ) -> Option<Address> {
+                stellar_axelar_gateway::executable::express_executor(env, source_chain, message_id, source_address, payload_hash)
+            }
+        }
+    }
+}
+
+fn error_alias(name: &Ident) -> Ident {
+    Ident::new(
+        &format!("__{}AxelarExecutableInterfaceError", name),
+        name.span(),
+    )
+}
+
+/// Generates the `AxelarExecutableInterface` implementation, running `execute_validated` once the message has been validated,
+/// either directly or with an inclusion proof.
+fn axelar_executable_interface(
+    name: &Ident,
+    error_alias: &Ident,
+    execute_validated: TokenStream2,
+) -> TokenStream2 {
+    quote! {
         impl stellar_axelar_gateway::executable::DeriveOnly for #name {}
 
         #[allow(non_camel_case_types)]
         type #error_alias = <#name as stellar_axelar_gateway::executable::CustomAxelarExecutable>::Error;
 
         #[contractimpl]
-        impl AxelarExecutableInterface for #name {
+        impl stellar_axelar_gateway::executable::AxelarExecutableInterface for #name {
             fn gateway(env: &Env) -> Address {
-                Self::__gateway(env)
+                <Self as stellar_axelar_gateway::executable::CustomAxelarExecutable>::__gateway(env)
             }
 
             fn execute(
@@ -32,7 +114,22 @@ pub fn axelar_executable(name: &Ident) -> TokenStream2 {
                     stellar_axelar_gateway::executable::ValidationError::NotApproved => #error_alias::NotApproved,
                 })?;
 
-                Self::__execute(env, source_chain, message_id, source_address, payload)
+                #execute_validated
+            }
+
+            fn execute_with_proof(
+                env: &Env,
+                source_chain: String,
+                message_id: String,
+                source_address: String,
+                payload: Bytes,
+                inclusion_proof: stellar_axelar_gateway::types::MessageInclusionProof,
+            ) -> Result<(), #error_alias> {
+                stellar_axelar_gateway::executable::validate_message_with_proof::<Self>(env, &source_chain, &message_id, &source_address, &payload, &inclusion_proof).map_err(|err| match err {
+                    stellar_axelar_gateway::executable::ValidationError::NotApproved => #error_alias::NotApproved,
+                })?;
+
+                #execute_validated
             }
         }
     }
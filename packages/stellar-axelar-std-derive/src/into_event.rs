@@ -131,7 +131,7 @@ type EventIdent<'a> = Vec<&'a Ident>;
 type EventType<'a> = Vec<&'a Type>;
 type EventStructFields<'a> = (EventIdent<'a>, EventType<'a>);
 
-fn event_struct_fields(input: &DeriveInput) -> (EventStructFields, EventStructFields) {
+fn event_struct_fields(input: &DeriveInput) -> (EventStructFields<'_>, EventStructFields<'_>) {
     let syn::Data::Struct(data_struct) = &input.data else {
         panic!("IntoEvent can only be derived for structs");
     };
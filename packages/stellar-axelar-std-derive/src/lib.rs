@@ -238,6 +238,10 @@ pub fn derive_its_executable(input: TokenStream) -> TokenStream {
     its_executable::its_executable(name).into()
 }
 
+/// Implements the `AxelarExecutableInterface` for a Soroban contract that implements `CustomAxelarExecutable`.
+///
+/// The contract's `CustomAxelarExecutable::Error` type must have a `NotApproved` variant,
+/// which is returned when the gateway hasn't approved the executed message.
 #[proc_macro_derive(AxelarExecutable)]
 pub fn derive_axelar_executable(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -246,6 +250,21 @@ pub fn derive_axelar_executable(input: TokenStream) -> TokenStream {
     axelar_executable::axelar_executable(name).into()
 }
 
+/// Implements the `AxelarExecutableInterface` and `AxelarExpressExecutableInterface` for a Soroban contract
+/// that implements `CustomAxelarExecutable` and `CustomAxelarExpressExecutable`.
+///
+/// The contract's `CustomAxelarExecutable::Error` type must have the following variants:
+/// - `NotApproved`: returned when the gateway hasn't approved the executed message.
+/// - `AlreadyApproved`: returned when express executing a message that the gateway has already approved.
+/// - `AlreadyExpressExecuted`: returned when express executing a message that has already been express executed.
+#[proc_macro_derive(AxelarExpressExecutable)]
+pub fn derive_axelar_express_executable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    axelar_executable::axelar_express_executable(name).into()
+}
+
 /// Ensures that only a contract's owner can execute the attributed function.
 ///
 /// The first argument to the function must be `env`
@@ -9,7 +9,7 @@ use syn::{Attribute, Data, DataEnum, DeriveInput, Fields, FieldsNamed, Meta, Typ
 
 enum Value {
     Status,
-    Type(Type),
+    Type(Box<Type>),
 }
 
 impl TryFrom<&[Attribute]> for Value {
@@ -25,10 +25,10 @@ impl TryFrom<&[Attribute]> for Value {
         if attr.path().is_ident("status") {
             Ok(Self::Status)
         } else if let Meta::List(list) = &attr.meta {
-            Ok(Self::Type(
+            Ok(Self::Type(Box::new(
                 list.parse_args::<Type>()
                     .map_err(|_| "failed to parse value type")?,
-            ))
+            )))
         } else {
             Err("value attribute must contain a type parameter: #[value(Type)]".into())
         }
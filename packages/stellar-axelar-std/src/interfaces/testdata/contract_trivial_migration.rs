@@ -1,6 +1,5 @@
 use core::convert::Infallible;
 
-use soroban_sdk::testutils::arbitrary::std;
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, String,
 };
@@ -25,6 +25,7 @@ extern crate std;
 ///     user,
 ///     source_gas_service_client.pay_gas(
 ///         source_app.address,
+///         message_id,
 ///         destination_chain,
 ///         destination_address,
 ///         payload,